@@ -0,0 +1,34 @@
+use aoc::days::DAYS;
+use common::input::InputManager;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmarks parsing and each part of every day with a cached or
+/// downloadable input, in a `day_N` group per day.
+fn days(c: &mut Criterion) {
+    let inputs = InputManager::from_env();
+    for day in DAYS {
+        let input = match inputs.get(day.number) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Day {} skipped: {}", day.number, err);
+                continue;
+            }
+        };
+        let solution = match (day.parse)(&input) {
+            Ok(solution) => solution,
+            Err(err) => {
+                eprintln!("Day {} skipped: {}", day.number, err);
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("day_{}", day.number));
+        group.bench_function("parse", |b| b.iter(|| (day.parse)(black_box(&input))));
+        group.bench_function("part_one", |b| b.iter(|| solution.answer(1)));
+        group.bench_function("part_two", |b| b.iter(|| solution.answer(2)));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,182 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+const HEADER: &str =
+    "# Confirmed answers, checked by `aoc verify` and added with `aoc verify --record`.
+# An answer without an `input` hash isn't tied to an input, so it's only advisory.
+";
+
+/// A confirmed answer to one part of a day, for the input with the given
+/// hash.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+    pub answer: String,
+}
+
+/// How a freshly computed answer compares with the registry.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Match,
+    Mismatch {
+        expected: String,
+    },
+    /// Only an answer without an input hash is recorded, which may be for
+    /// some other input, so it's advice rather than a check.
+    Unpinned {
+        expected: String,
+    },
+    Unrecorded,
+}
+
+/// The answers recorded in `answers.toml`.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Registry {
+    #[serde(default, rename = "answer")]
+    answers: Vec<Answer>,
+}
+
+/// A short SHA-256 of the input, enough to tell inputs apart.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .take(8)
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+impl Registry {
+    /// Reads the registry, or an empty one if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to read {}", path.display()))
+            }
+        };
+        toml::from_str(&text).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&mut self, path: &Path) -> Result<()> {
+        self.answers.sort();
+        let text = toml::to_string(self)?;
+        fs::write(path, format!("{}\n{}", HEADER, text))
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// The answer recorded for the input with this hash, or with `None`,
+    /// the one recorded without a hash.
+    fn find(&self, day: u8, part: u8, hash: Option<&str>) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.input.as_deref() == hash)
+            .map(|a| a.answer.as_str())
+    }
+
+    /// The recorded answer for this input.
+    pub fn expected(&self, day: u8, part: u8, hash: &str) -> Option<&str> {
+        self.find(day, part, Some(hash))
+    }
+
+    pub fn check(&self, day: u8, part: u8, hash: &str, answer: &str) -> Check {
+        match self.expected(day, part, hash) {
+            Some(expected) if expected == answer => Check::Match,
+            Some(expected) => Check::Mismatch {
+                expected: expected.to_string(),
+            },
+            None => match self.find(day, part, None) {
+                Some(expected) => Check::Unpinned {
+                    expected: expected.to_string(),
+                },
+                None => Check::Unrecorded,
+            },
+        }
+    }
+
+    /// Stores `answer` for this input, replacing any answer recorded for it
+    /// before. An answer without a hash is left alone.
+    pub fn record(&mut self, day: u8, part: u8, hash: &str, answer: &str) {
+        self.answers
+            .retain(|a| !(a.day == day && a.part == part && a.input.as_deref() == Some(hash)));
+        self.answers.push(Answer {
+            day,
+            part,
+            input: Some(hash.to_string()),
+            answer: answer.to_string(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    const ANSWERS: &str = r#"
+[[answer]]
+day = 7
+part = 1
+answer = "6440"
+
+[[answer]]
+day = 7
+part = 1
+input = "00112233"
+answer = "1"
+"#;
+
+    #[test]
+    fn test_check() {
+        let registry: Registry = toml::from_str(ANSWERS).unwrap();
+        assert_eq!(registry.check(7, 1, "00112233", "1"), Check::Match);
+        assert_eq!(
+            registry.check(7, 1, "00112233", "6440"),
+            Check::Mismatch {
+                expected: "1".to_string()
+            }
+        );
+        // the unhashed answer may be for another input, so it's only advice
+        assert_eq!(
+            registry.check(7, 1, "ffffffff", "6441"),
+            Check::Unpinned {
+                expected: "6440".to_string()
+            }
+        );
+        assert_eq!(registry.check(7, 2, "ffffffff", "5905"), Check::Unrecorded);
+    }
+
+    #[test]
+    fn test_record() {
+        let mut registry: Registry = toml::from_str(ANSWERS).unwrap();
+        registry.record(7, 1, "ffffffff", "6441");
+        registry.record(7, 1, "00112233", "2");
+        assert_eq!(registry.expected(7, 1, "ffffffff"), Some("6441"));
+        assert_eq!(registry.expected(7, 1, "00112233"), Some("2"));
+        assert_eq!(registry.expected(7, 1, "44556677"), None);
+        // recording for an input keeps the unhashed answer
+        assert_eq!(
+            registry.check(7, 1, "44556677", "6440"),
+            Check::Unpinned {
+                expected: "6440".to_string()
+            }
+        );
+
+        let path = std::env::temp_dir().join(format!("answers-{}.toml", std::process::id()));
+        registry.save(&path).unwrap();
+        assert_eq!(Registry::load(&path).unwrap(), registry);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash("abc"), "ba7816bf8f01cfea");
+        assert_ne!(input_hash("abc\n"), input_hash("abc"));
+    }
+}
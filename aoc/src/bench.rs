@@ -0,0 +1,173 @@
+use crate::days::Day;
+use common::ParseError;
+use serde::Serialize;
+use std::fmt::{self, Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// Median times for each phase of one day, over a number of runs.
+#[derive(Clone, Debug, Serialize)]
+pub struct Timing {
+    pub day: u8,
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse: Duration,
+    #[serde(rename = "part_one_ns", serialize_with = "nanos")]
+    pub part_one: Duration,
+    #[serde(rename = "part_two_ns", serialize_with = "nanos")]
+    pub part_two: Duration,
+}
+
+fn nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Times parsing and each part separately, `runs` times over.
+pub fn bench_day(day: &Day, input: &str, runs: usize) -> Result<Timing, ParseError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut part_one = Vec::with_capacity(runs);
+    let mut part_two = Vec::with_capacity(runs);
+
+    for _ in 0..runs.max(1) {
+        let (solution, elapsed) = time(|| (day.parse)(input));
+        let solution = solution?;
+        parse.push(elapsed);
+        part_one.push(time(|| solution.answer(1)).1);
+        part_two.push(time(|| solution.answer(2)).1);
+    }
+
+    Ok(Timing {
+        day: day.number,
+        parse: median(parse),
+        part_one: median(part_one),
+        part_two: median(part_two),
+    })
+}
+
+/// A duration in the largest unit that keeps it above one.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{} ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.2} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else {
+        format!("{:.2} s", nanos / 1e9)
+    }
+}
+
+/// The timings for a whole run, as printed and exported.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    /// Seconds since the Unix epoch when the run started.
+    pub timestamp: u64,
+    pub runs: usize,
+    pub days: Vec<Timing>,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let header = ["Day", "Parse", "Part 1", "Part 2", "Total"].map(String::from);
+        let rows = self.days.iter().map(|timing| {
+            [
+                timing.day.to_string(),
+                format_duration(timing.parse),
+                format_duration(timing.part_one),
+                format_duration(timing.part_two),
+                format_duration(timing.total()),
+            ]
+        });
+        let total = self.days.iter().map(Timing::total).sum();
+        let footer = [
+            "All".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            format_duration(total),
+        ];
+        let rows = std::iter::once(header)
+            .chain(rows)
+            .chain(std::iter::once(footer))
+            .collect::<Vec<_>>();
+
+        let mut widths = [0; 5];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            // the day left aligned, the times right aligned
+            let cells = row
+                .iter()
+                .zip(widths)
+                .enumerate()
+                .map(|(column, (cell, width))| match column {
+                    0 => format!("{:<width$}", cell, width = width),
+                    _ => format!("{:>width$}", cell, width = width),
+                })
+                .collect::<Vec<_>>();
+            write!(f, "{}", cells.join("  "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+    use crate::days;
+
+    #[test]
+    fn test_bench_day() {
+        let day = days::find(9).unwrap();
+        let timing = bench_day(day, "0 3 6 9 12 15", 3).unwrap();
+        assert_eq!(timing.day, 9);
+        assert!(bench_day(day, "0 3 x", 3).is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let report = Report {
+            timestamp: 0,
+            runs: 1,
+            days: vec![Timing {
+                day: 6,
+                parse: Duration::from_nanos(850),
+                part_one: Duration::from_micros(12),
+                part_two: Duration::from_millis(35),
+            }],
+        };
+        assert_eq!(
+            report.to_string(),
+            "Day   Parse    Part 1    Part 2     Total
+6    850 ns  12.00 µs  35.00 ms  35.01 ms
+All                              35.01 ms"
+        );
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"timestamp":0,"runs":1,"days":[{"day":6,"parse_ns":850,"part_one_ns":12000,"part_two_ns":35000000}]}"#
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use common::{parse_dyn, DynSolution, ParseError};
+
+pub struct Day {
+    pub number: u8,
+    pub parse: fn(&str) -> Result<Box<dyn DynSolution>, ParseError>,
+}
+
+impl Day {
+    /// Parses `input` and solves the requested parts, in order.
+    pub fn solve(&self, input: &str, parts: &[u8]) -> Result<Vec<String>, ParseError> {
+        let solution = (self.parse)(input)?;
+        Ok(parts.iter().map(|&part| solution.answer(part)).collect())
+    }
+
+    /// Whether `part` is a puzzle with an answer to submit. The last day's
+    /// second star comes free with the other 49.
+    pub fn has_puzzle(&self, part: u8) -> bool {
+        !(self.number == 25 && part == 2)
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        parse: parse_dyn::<day_1::CalibrationDocument>,
+    },
+    Day {
+        number: 2,
+        parse: parse_dyn::<day_2::GameRecord>,
+    },
+    Day {
+        number: 3,
+        parse: parse_dyn::<day_3::Schematic>,
+    },
+    Day {
+        number: 4,
+        parse: parse_dyn::<day_4::Pile>,
+    },
+    Day {
+        number: 5,
+        parse: parse_dyn::<day_5::Almanac>,
+    },
+    Day {
+        number: 6,
+        parse: parse_dyn::<day_6::RaceSheet>,
+    },
+    Day {
+        number: 7,
+        parse: parse_dyn::<day_7::CamelCards>,
+    },
+    Day {
+        number: 8,
+        parse: parse_dyn::<day_8::Network>,
+    },
+    Day {
+        number: 9,
+        parse: parse_dyn::<day_9::Report>,
+    },
+    Day {
+        number: 10,
+        parse: parse_dyn::<day_10::PipeMap>,
+    },
+    Day {
+        number: 11,
+        parse: parse_dyn::<day_11::Image>,
+    },
+    Day {
+        number: 12,
+        parse: parse_dyn::<day_12::Records>,
+    },
+    Day {
+        number: 13,
+        parse: parse_dyn::<day_13::Notes>,
+    },
+    Day {
+        number: 14,
+        parse: parse_dyn::<day_14::Platform>,
+    },
+    Day {
+        number: 15,
+        parse: parse_dyn::<day_15::InitSequence>,
+    },
+    Day {
+        number: 16,
+        parse: parse_dyn::<day_16::Contraption>,
+    },
+    Day {
+        number: 17,
+        parse: parse_dyn::<day_17::City>,
+    },
+    Day {
+        number: 18,
+        parse: parse_dyn::<day_18::DigPlan>,
+    },
+    Day {
+        number: 19,
+        parse: parse_dyn::<day_19::System>,
+    },
+    Day {
+        number: 20,
+        parse: parse_dyn::<day_20::Network>,
+    },
+    Day {
+        number: 21,
+        parse: parse_dyn::<day_21::Garden>,
+    },
+    Day {
+        number: 22,
+        parse: parse_dyn::<day_22::Snapshot>,
+    },
+    Day {
+        number: 23,
+        parse: parse_dyn::<day_23::Trails>,
+    },
+    Day {
+        number: 24,
+        parse: parse_dyn::<day_24::Hailstorm>,
+    },
+    Day {
+        number: 25,
+        parse: parse_dyn::<day_25::Wiring>,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,8 @@
+//! The runner's building blocks, shared by the `aoc` binary and the
+//! benchmarks.
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod scaffold;
+pub mod submit;
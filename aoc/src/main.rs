@@ -0,0 +1,360 @@
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Parser, Subcommand};
+use common::input::InputManager;
+use common::run::{AnswerTable, Source};
+use common::NO_ANSWER;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc::answers::{input_hash, Check, Registry};
+use aoc::bench::{bench_day, Report};
+use aoc::days::{self, Day, DAYS};
+use aoc::scaffold::new_day;
+use aoc::submit::{Guesses, Submitter, Verdict};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day when no day is given
+    Run {
+        day: Option<u8>,
+        /// Only run the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle inputs to solve in turn instead of the cached puzzle input, `-`
+        /// reading stdin
+        #[arg(long, short, requires = "day", num_args = 1..)]
+        input: Vec<Source>,
+    },
+    /// Recompute every day, or one day, and compare with the recorded answers
+    Verify {
+        day: Option<u8>,
+        /// Store the computed answers, replacing any that differ
+        #[arg(long)]
+        record: bool,
+        /// The answer registry
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Time parsing and each part of every day, or one day
+    Bench {
+        day: Option<u8>,
+        /// How many times to run each day, reporting the median
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Also write the timings as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// Submit the answer computed for a day's part
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// The answer registry, where a correct answer is recorded
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Create the crate for a new day and register it with the workspace
+    /// and the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// The name of the type holding the parsed input
+        #[arg(long, default_value = "Puzzle")]
+        model: String,
+        /// The puzzle's title, for the crate's doc comment
+        #[arg(long)]
+        title: Option<String>,
+    },
+}
+
+fn default_input(day: &Day) -> Source {
+    Source::Puzzle(day.number)
+}
+
+fn solve_input(day: &Day, parts: &[u8], source: &Source) -> Result<Vec<String>> {
+    let input = source
+        .read()
+        .with_context(|| format!("Failed to read input {}", source))?;
+    day.solve(&input, parts)
+        .map_err(|err| anyhow!(err.report(source, &input)))
+}
+
+/// Solves each input in turn, printing a table with a row per input.
+fn run_day(day: &Day, part: Option<u8>, sources: &[Source]) -> Result<()> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut table = AnswerTable::new(&parts);
+    let mut failures = 0;
+    for source in sources {
+        match solve_input(day, &parts, source) {
+            Ok(answers) => table.push(source, answers),
+            Err(err) => {
+                eprintln!("error: {:#}", err);
+                table.push_failed(source);
+                failures += 1;
+            }
+        }
+    }
+
+    println!("Day {}", day.number);
+    println!("{}", table);
+    if failures > 0 {
+        bail!(
+            "Day {} failed on {} of {} inputs",
+            day.number,
+            failures,
+            sources.len()
+        );
+    }
+    Ok(())
+}
+
+/// Checks a day's answers against the registry, or records them, returning
+/// how many differ. A part with no answer is never recorded, and counts as
+/// differing.
+fn verify_day(day: &Day, registry: &mut Registry, record: bool) -> Result<usize> {
+    let source = default_input(day);
+    let input = source
+        .read()
+        .with_context(|| format!("Failed to read input {}", source))?;
+    let hash = input_hash(&input);
+    let parts = [1, 2];
+    let answers = day
+        .solve(&input, &parts)
+        .map_err(|err| anyhow!(err.report(&source, &input)))?;
+
+    let mut mismatches = 0;
+    for (part, answer) in parts.into_iter().zip(answers) {
+        if answer == NO_ANSWER {
+            mismatches += 1;
+            println!("Day {} part {}: NO ANSWER", day.number, part);
+            continue;
+        }
+        let status = match registry.check(day.number, part, &hash, &answer) {
+            Check::Match => "ok".to_string(),
+            Check::Mismatch { expected } if record => format!("updated, was {}", expected),
+            Check::Mismatch { expected } => {
+                mismatches += 1;
+                format!("MISMATCH, expected {}", expected)
+            }
+            Check::Unpinned { expected } if expected == answer => "ok, unpinned".to_string(),
+            Check::Unpinned { expected } if record => {
+                format!("recorded, unpinned answer is {}", expected)
+            }
+            // not counted: the unpinned answer may be for another input
+            Check::Unpinned { expected } => {
+                format!("differs from unpinned answer {}", expected)
+            }
+            Check::Unrecorded if record => "recorded".to_string(),
+            Check::Unrecorded => "unrecorded".to_string(),
+        };
+        if record {
+            registry.record(day.number, part, &hash, &answer);
+        }
+        println!("Day {} part {}: {} ({})", day.number, part, answer, status);
+    }
+    Ok(mismatches)
+}
+
+fn verify(days: &[Day], answers: &Path, record: bool) -> Result<()> {
+    let mut registry = Registry::load(answers)?;
+    let mut mismatches = 0;
+    for day in days {
+        match verify_day(day, &mut registry, record) {
+            Ok(count) => mismatches += count,
+            // a day without its input can't be checked, but isn't wrong
+            Err(err) => eprintln!("Day {} skipped: {:#}", day.number, err),
+        }
+    }
+
+    if record {
+        registry.save(answers)?;
+    }
+    if mismatches > 0 {
+        bail!("{} answers differ from {}", mismatches, answers.display());
+    }
+    Ok(())
+}
+
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+fn bench(days: &[Day], runs: usize, json: Option<&Path>) -> Result<()> {
+    let mut report = Report {
+        timestamp: unix_time(),
+        runs,
+        days: Vec::new(),
+    };
+
+    for day in days {
+        let source = default_input(day);
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Day {} skipped: {}", day.number, err);
+                continue;
+            }
+        };
+        match bench_day(day, &input, runs) {
+            Ok(timing) => report.days.push(timing),
+            Err(err) => eprintln!("error: {}", err.report(&source, &input)),
+        }
+    }
+
+    println!("{}", report);
+    if let Some(path) = json {
+        let file =
+            File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+        serde_json::to_writer_pretty(file, &report)?;
+    }
+    Ok(())
+}
+
+/// Submits the day's answer for `part`, unless it's known to be right or
+/// wrong already.
+fn submit(day: &Day, part: u8, answers: &Path) -> Result<()> {
+    if !day.has_puzzle(part) {
+        bail!("Day {} has no part {} puzzle to submit", day.number, part);
+    }
+    let source = default_input(day);
+    let input = source
+        .read()
+        .with_context(|| format!("Failed to read input {}", source))?;
+    let hash = input_hash(&input);
+    let answer = day
+        .solve(&input, &[part])
+        .map_err(|err| anyhow!(err.report(&source, &input)))?
+        .remove(0);
+    if answer == NO_ANSWER {
+        bail!("Day {} part {} found no answer to submit", day.number, part);
+    }
+
+    let mut registry = Registry::load(answers)?;
+    match registry.check(day.number, part, &hash, &answer) {
+        Check::Match => {
+            println!(
+                "Day {} part {}: {} is already confirmed",
+                day.number, part, answer
+            );
+            return Ok(());
+        }
+        Check::Mismatch { expected } => {
+            bail!("{} differs from the confirmed answer {}", answer, expected)
+        }
+        // an unpinned answer may be for another input, so the site decides
+        Check::Unpinned { .. } | Check::Unrecorded => (),
+    }
+
+    let guesses_path = InputManager::from_env()
+        .cache_dir()
+        .join("submissions.toml");
+    let mut guesses = Guesses::load(&guesses_path)?;
+    if let Some(reason) = guesses.refusal(day.number, part, &answer, unix_time()) {
+        bail!("Not submitting, {}", reason);
+    }
+
+    let submitter = Submitter::from_env()
+        .context("No session cookie, set AOC_SESSION or write it to ~/.config/aoc/session")?;
+    println!("Day {} part {}: submitting {}", day.number, part, answer);
+    let verdict = submitter.submit(day.number, part, &answer)?;
+    guesses.record(day.number, part, &answer, &verdict, unix_time());
+    guesses.save(&guesses_path)?;
+
+    match verdict {
+        Verdict::Correct => {
+            registry.record(day.number, part, &hash, &answer);
+            registry.save(answers)?;
+            println!("{}", verdict);
+        }
+        Verdict::WrongLevel => println!("{}", verdict),
+        _ => bail!("{}", verdict),
+    }
+    Ok(())
+}
+
+/// The one day asked for, or every day.
+fn select_days(day: Option<u8>) -> Result<&'static [Day]> {
+    match day {
+        Some(number) => match days::find(number) {
+            Some(day) => Ok(std::slice::from_ref(day)),
+            None => bail!("No solution for day {}", number),
+        },
+        None => Ok(DAYS),
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            mut input,
+        } => match day {
+            Some(number) => {
+                let Some(day) = days::find(number) else {
+                    bail!("No solution for day {}", number);
+                };
+                if input.is_empty() {
+                    input.push(default_input(day));
+                }
+                run_day(day, part, &input)?;
+            }
+            None => {
+                for day in DAYS {
+                    if let Err(err) = run_day(day, part, &[default_input(day)]) {
+                        eprintln!("error: {:#}", err);
+                    }
+                }
+            }
+        },
+        Command::Verify {
+            day,
+            record,
+            answers,
+        } => verify(select_days(day)?, &answers, record)?,
+        Command::Bench { day, runs, json } => bench(select_days(day)?, runs, json.as_deref())?,
+        Command::Submit { day, part, answers } => {
+            let Some(day) = days::find(day) else {
+                bail!("No solution for day {}", day);
+            };
+            submit(day, part, &answers)?;
+        }
+        Command::New { day, model, title } => {
+            new_day(Path::new("."), day, &model, title.as_deref())?;
+            println!(
+                "Created day_{0}, fill in the example in day_{0}/src/lib.rs",
+                day
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    // Errors are printed rather than returned so a bad input gets a short
+    // diagnostic instead of a debug dump and backtrace
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
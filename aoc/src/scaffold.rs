@@ -0,0 +1,307 @@
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+
+const MANIFEST: &str = r#"[package]
+name = "day_{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = { path = "../common" }
+"#;
+
+const MAIN: &str = "use day_{day}::{model};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<{model}>({day})
+}
+";
+
+const LIB: &str = r#"//! {title}
+
+use common::{ParseError, Solution};
+
+/// The puzzle input, one entry per line.
+pub struct {model} {
+    pub lines: Vec<String>,
+}
+
+impl Solution for {model} {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let lines = input.lines().map(String::from).collect();
+        Ok(Self { lines })
+    }
+
+    fn part_one(&self) -> usize {
+        0
+    }
+
+    fn part_two(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn test_part_1() {
+        let result = {model}::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn test_part_2() {
+        let result = {model}::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 0);
+    }
+}
+"#;
+
+fn render(template: &str, day: u8, model: &str, title: &str) -> String {
+    template
+        .replace("{day}", &day.to_string())
+        .replace("{model}", model)
+        .replace("{title}", title)
+}
+
+/// Inserts `entry` among the per-day entries of `text`, keeping them in day
+/// order. `number_of` picks out the day from the line that names it, and
+/// each entry spans `len` lines starting `above` lines before that one.
+fn insert_in_order<F>(
+    text: &str,
+    day: u8,
+    entry: &str,
+    number_of: F,
+    above: usize,
+    len: usize,
+) -> Result<String>
+where
+    F: Fn(&str) -> Option<u8>,
+{
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, number_of(line)?)))
+        .collect::<Vec<_>>();
+
+    if days.iter().any(|&(_, number)| number == day) {
+        bail!("Day {} is already registered", day);
+    }
+    let at = match days.iter().find(|&&(_, number)| number > day) {
+        Some(&(i, _)) => i - above,
+        None => match days.last() {
+            Some(&(i, _)) => i - above + len,
+            None => bail!("No days to register day {} alongside", day),
+        },
+    };
+
+    for (offset, line) in entry.lines().enumerate() {
+        lines.insert(at + offset, line);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the crate to the workspace `members`.
+pub fn register_member(manifest: &str, day: u8) -> Result<String> {
+    let entry = format!("    \"day_{}\",", day);
+    insert_in_order(
+        manifest,
+        day,
+        &entry,
+        |line| {
+            line.trim()
+                .strip_prefix("\"day_")?
+                .strip_suffix("\",")?
+                .parse()
+                .ok()
+        },
+        0,
+        1,
+    )
+}
+
+/// Adds the crate to the runner's dependencies.
+pub fn register_dependency(manifest: &str, day: u8) -> Result<String> {
+    let entry = format!("day_{0} = {{ path = \"../day_{0}\" }}", day);
+    insert_in_order(
+        manifest,
+        day,
+        &entry,
+        |line| {
+            line.strip_prefix("day_")?
+                .split_once(" = { path")?
+                .0
+                .parse()
+                .ok()
+        },
+        0,
+        1,
+    )
+}
+
+/// Adds the day to the runner's `DAYS` table.
+pub fn register_day(days: &str, day: u8, model: &str) -> Result<String> {
+    let entry = format!(
+        "    Day {{\n        number: {0},\n        parse: parse_dyn::<day_{0}::{1}>,\n    }},",
+        day, model
+    );
+    insert_in_order(
+        days,
+        day,
+        &entry,
+        |line| {
+            line.strip_prefix("        number: ")?
+                .strip_suffix(',')?
+                .parse()
+                .ok()
+        },
+        1,
+        4,
+    )
+}
+
+fn update(path: &Path, f: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    fs::write(path, f(&text)?).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Creates the `day_N` crate under the workspace at `root`, with a model
+/// named `model`, and registers it in the workspace and the runner.
+pub fn new_day(root: &Path, day: u8, model: &str, title: Option<&str>) -> Result<()> {
+    let is_type_name = model.starts_with(|c: char| c.is_ascii_uppercase())
+        && model.chars().all(|c| c.is_ascii_alphanumeric());
+    if !is_type_name {
+        bail!("{} isn't a type name", model);
+    }
+    let dir = root.join(format!("day_{}", day));
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    // register first, so a failure leaves no half-made crate behind
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc/Cargo.toml");
+    let days = root.join("aoc/src/days.rs");
+    let registered = [
+        fs::read_to_string(&workspace).map(|text| register_member(&text, day)),
+        fs::read_to_string(&runner).map(|text| register_dependency(&text, day)),
+        fs::read_to_string(&days).map(|text| register_day(&text, day, model)),
+    ];
+    for result in registered {
+        result.context("Failed to read the workspace, run this from its root")??;
+    }
+
+    let title = match title {
+        Some(title) => format!("Day {}: {}", day, title),
+        None => format!("Day {}", day),
+    };
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), render(MANIFEST, day, model, &title))?;
+    fs::write(dir.join("src/main.rs"), render(MAIN, day, model, &title))?;
+    fs::write(dir.join("src/lib.rs"), render(LIB, day, model, &title))?;
+
+    update(&workspace, |text| register_member(text, day))?;
+    update(&runner, |text| register_dependency(text, day))?;
+    update(&days, |text| register_day(text, day, model))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::*;
+
+    const WORKSPACE: &str = r#"[workspace]
+resolver = "2"
+members = [
+    "aoc",
+    "common",
+    "day_1",
+    "day_13",
+]
+"#;
+
+    const RUNNER: &str = r#"[dependencies]
+common = { path = "../common" }
+day_1 = { path = "../day_1" }
+day_13 = { path = "../day_13" }
+serde = "1.0.193"
+"#;
+
+    const DAYS: &str = "pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        parse: parse_dyn::<day_1::CalibrationDocument>,
+    },
+    Day {
+        number: 13,
+        parse: parse_dyn::<day_13::Notes>,
+    },
+];
+";
+
+    #[test]
+    fn test_register() {
+        let workspace = register_member(WORKSPACE, 14).unwrap();
+        assert!(workspace.contains("    \"day_13\",\n    \"day_14\",\n]"));
+        let workspace = register_member(&workspace, 12).unwrap();
+        assert!(workspace.contains("    \"day_1\",\n    \"day_12\",\n    \"day_13\","));
+
+        let runner = register_dependency(RUNNER, 12).unwrap();
+        assert!(runner.contains(
+            "day_1 = { path = \"../day_1\" }\nday_12 = { path = \"../day_12\" }\nday_13"
+        ));
+
+        let days = register_day(DAYS, 14, "Platform").unwrap();
+        assert!(days.ends_with(
+            "    Day {
+        number: 14,
+        parse: parse_dyn::<day_14::Platform>,
+    },
+];
+"
+        ));
+        let days = register_day(&days, 2, "GameRecord").unwrap();
+        assert!(days.contains("    },\n    Day {\n        number: 2,\n"));
+
+        assert!(register_day(DAYS, 13, "Notes").is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), RUNNER).unwrap();
+        fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+
+        new_day(&root, 14, "Platform", Some("Parabolic Reflector Dish")).unwrap();
+        let lib = fs::read_to_string(root.join("day_14/src/lib.rs")).unwrap();
+        assert!(lib.starts_with("//! Day 14: Parabolic Reflector Dish\n"));
+        assert!(lib.contains("pub struct Platform {"));
+        let main = fs::read_to_string(root.join("day_14/src/main.rs")).unwrap();
+        assert!(main.contains("common::run::main::<Platform>(14)"));
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"day_14\""));
+
+        assert!(new_day(&root, 14, "Platform", None).is_err());
+        assert!(new_day(&root, 15, "lens_library", None).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,372 @@
+use anyhow::{anyhow, Context, Result};
+use common::input::{base_url_from_env, session_from_env, USER_AGENT, YEAR};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+
+/// What the site said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// With how long the site asks to wait before answering again.
+    Wrong(Option<Hint>, Duration),
+    /// Submitted too soon after the last attempt.
+    Wait(Duration),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    /// Anything else, as the text of the response.
+    Unknown(String),
+}
+
+/// Which side of the answer a wrong guess was on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::Wrong(Some(hint), _) => {
+                write!(f, "That's not the right answer, it's {}.", hint)
+            }
+            Verdict::Wrong(None, _) => write!(f, "That's not the right answer."),
+            Verdict::Wait(wait) => write!(f, "Answered too recently, wait {}s.", wait.as_secs()),
+            Verdict::WrongLevel => write!(f, "That part is already solved or still locked."),
+            Verdict::Unknown(text) => write!(f, "Unexpected response: {}", text),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, which holds the message, with tags
+/// dropped.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses a wait like `1m 23s` or `45s`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    for token in text.split_whitespace() {
+        let (number, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Parses a wait in words like `one minute` or `5 minutes`.
+fn parse_wait_words(text: &str) -> Option<Duration> {
+    let (number, unit) = text.split_once(' ')?;
+    let number: u64 = match number {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    let unit = match unit.trim_end_matches('s') {
+        "hour" => 3600,
+        "minute" => 60,
+        "second" => 1,
+        _ => return None,
+    };
+    Some(Duration::from_secs(number * unit))
+}
+
+/// Works out the verdict from the HTML the site returns for a submission.
+pub fn parse_response(html: &str) -> Verdict {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        let wait = text
+            .split_once("wait ")
+            .and_then(|(_, rest)| rest.split_once(" before trying again"))
+            .and_then(|(wait, _)| parse_wait_words(wait))
+            .unwrap_or(Duration::from_secs(60));
+        Verdict::Wrong(hint, wait)
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait))
+            .unwrap_or(Duration::from_secs(60));
+        Verdict::Wait(wait)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+/// Posts answers to the site with a session cookie.
+pub struct Submitter {
+    base_url: String,
+    session: String,
+}
+
+impl Submitter {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// A submitter for `AOC_URL` or the real site, using the session cookie
+    /// the input fetcher uses.
+    pub fn from_env() -> Option<Self> {
+        Some(Self::new(base_url_from_env(), session_from_env()?))
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| anyhow!("Failed to submit to {}: {}", url, err))?;
+        let html = response.into_string()?;
+        Ok(parse_response(&html))
+    }
+}
+
+/// A wrong answer the site already rejected.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+/// Every wrong guess so far, and when the site will accept answers again.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guesses {
+    /// Seconds since the Unix epoch before which submitting is pointless.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+    #[serde(default, rename = "guess")]
+    pub guesses: Vec<Guess>,
+}
+
+impl Guesses {
+    /// Reads the guesses, or none if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to read {}", path.display()))
+            }
+        };
+        toml::from_str(&text).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Why `answer` shouldn't be submitted at `now`, if it shouldn't: it was
+    /// already rejected, it's outside the bounds earlier hints give, or the
+    /// site is still asking us to wait.
+    pub fn refusal(&self, day: u8, part: u8, answer: &str, now: u64) -> Option<String> {
+        let guesses = self
+            .guesses
+            .iter()
+            .filter(|g| g.day == day && g.part == part);
+        let number = answer.parse::<i64>().ok();
+        for guess in guesses {
+            if guess.answer == answer {
+                return Some(match guess.hint {
+                    Some(hint) => format!("{} was already rejected as {}", answer, hint),
+                    None => format!("{} was already rejected", answer),
+                });
+            }
+            let (Some(number), Ok(guessed)) = (number, guess.answer.parse::<i64>()) else {
+                continue;
+            };
+            match guess.hint {
+                Some(Hint::TooHigh) if number >= guessed => {
+                    return Some(format!("{} is too high, {} already was", answer, guessed))
+                }
+                Some(Hint::TooLow) if number <= guessed => {
+                    return Some(format!("{} is too low, {} already was", answer, guessed))
+                }
+                _ => (),
+            }
+        }
+
+        match self.wait_until {
+            Some(until) if until > now => Some(format!("wait another {}s", until - now)),
+            _ => None,
+        }
+    }
+
+    /// Remembers what the site said about `answer`.
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, verdict: &Verdict, now: u64) {
+        match verdict {
+            Verdict::Wrong(hint, wait) => {
+                self.guesses.push(Guess {
+                    day,
+                    part,
+                    answer: answer.to_string(),
+                    hint: *hint,
+                });
+                self.wait_until = Some(now + wait.as_secs());
+            }
+            Verdict::Wait(wait) => self.wait_until = Some(now + wait.as_secs()),
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::submit::*;
+    use common::stand_in::StandIn;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let correct = page("That's the right answer! You are <span>one gold star</span> closer.");
+        assert_eq!(parse_response(&correct), Verdict::Correct);
+        let high = page("That's not the right answer; your answer is too high. Please wait one minute before trying again.");
+        assert_eq!(
+            parse_response(&high),
+            Verdict::Wrong(Some(Hint::TooHigh), Duration::from_secs(60))
+        );
+        let low = page("That's not the right answer; your answer is too low. Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.");
+        assert_eq!(
+            parse_response(&low),
+            Verdict::Wrong(Some(Hint::TooLow), Duration::from_secs(300))
+        );
+        let wrong = page("That's not the right answer. If you're stuck, make sure you're using the full input data.");
+        assert_eq!(
+            parse_response(&wrong),
+            Verdict::Wrong(None, Duration::from_secs(60))
+        );
+        let wait = page("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 23s left to wait.");
+        assert_eq!(
+            parse_response(&wait),
+            Verdict::Wait(Duration::from_secs(83))
+        );
+        let level =
+            page("You don't seem to be solving the right level. Did you already complete it?");
+        assert_eq!(parse_response(&level), Verdict::WrongLevel);
+        assert_eq!(
+            parse_response(&page("Something <em>else</em>.")),
+            Verdict::Unknown("Something else.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_refusal() {
+        let mut guesses = Guesses::default();
+        let minute = Duration::from_secs(60);
+        guesses.record(1, 1, "500", &Verdict::Wrong(Some(Hint::TooHigh), minute), 0);
+        guesses.record(1, 1, "100", &Verdict::Wrong(Some(Hint::TooLow), minute), 0);
+        guesses.record(1, 2, "abc", &Verdict::Wrong(None, minute), 0);
+
+        // a wrong answer also means waiting before the next one
+        assert_eq!(
+            guesses.refusal(1, 1, "250", 30),
+            Some("wait another 30s".to_string())
+        );
+
+        assert!(guesses.refusal(1, 1, "500", 60).is_some());
+        assert!(guesses.refusal(1, 1, "700", 60).is_some());
+        assert!(guesses.refusal(1, 1, "50", 60).is_some());
+        assert_eq!(guesses.refusal(1, 1, "250", 60), None);
+        assert!(guesses.refusal(1, 2, "abc", 60).is_some());
+        assert_eq!(guesses.refusal(2, 1, "500", 60), None);
+
+        guesses.record(1, 1, "250", &Verdict::Wait(Duration::from_secs(30)), 1000);
+        assert_eq!(
+            guesses.refusal(1, 1, "250", 1010),
+            Some("wait another 20s".to_string())
+        );
+        assert_eq!(guesses.refusal(1, 1, "250", 1030), None);
+
+        let path = std::env::temp_dir().join(format!("guesses-{}.toml", std::process::id()));
+        guesses.save(&path).unwrap();
+        assert_eq!(Guesses::load(&path).unwrap(), guesses);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = StandIn::serve(vec![
+            (
+                200,
+                page("That's not the right answer; your answer is too low."),
+            ),
+            (200, page("That's the right answer!")),
+        ]);
+        let submitter = Submitter::new(server.url(), "abc123");
+        assert_eq!(
+            submitter.submit(7, 2, "100").unwrap(),
+            Verdict::Wrong(Some(Hint::TooLow), Duration::from_secs(60))
+        );
+        assert_eq!(submitter.submit(7, 2, "5905").unwrap(), Verdict::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/7/answer");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+        assert_eq!(requests[0].user_agent.as_deref(), Some(USER_AGENT));
+        assert_eq!(requests[1].body, "level=2&answer=5905");
+    }
+}
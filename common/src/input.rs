@@ -0,0 +1,252 @@
+//! Puzzle inputs, read from a local cache and downloaded on a cache miss.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// The event year, used in the site's URLs and the cache directory.
+pub const YEAR: u16 = 2023;
+const BASE_URL: &str = "https://adventofcode.com";
+/// How every request to the site identifies this tool.
+pub const USER_AGENT: &str = "advent_of_code_2023 aoc runner (ureq)";
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    /// The input isn't cached and there's no session cookie to download it.
+    NoSession,
+    /// The server answered with something other than the input.
+    Http {
+        status: u16,
+        message: String,
+    },
+    /// The server couldn't be reached.
+    Transport(String),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(err) => write!(f, "{}", err),
+            InputError::NoSession => write!(
+                f,
+                "input not cached and no session cookie, set AOC_SESSION or write it to {}",
+                session_path().map_or("~/.config/aoc/session".into(), |p| p.display().to_string())
+            ),
+            InputError::Http { status, message } => write!(f, "HTTP {}: {}", status, message),
+            InputError::Transport(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::Io(err)
+    }
+}
+
+/// Somewhere a day's input can be downloaded from.
+pub trait Fetch {
+    fn fetch(&self, day: u8) -> Result<String, InputError>;
+}
+
+/// Downloads inputs from the Advent of Code site, authenticating with the
+/// `session` cookie of a logged in browser.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+impl HttpFetcher {
+    pub fn new(session: impl Into<String>) -> Self {
+        Self::with_base_url(BASE_URL, session)
+    }
+
+    /// A fetcher for a server other than the real site, e.g. a local
+    /// stand-in.
+    pub fn with_base_url(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// A fetcher using the session cookie from `AOC_SESSION` or
+    /// `~/.config/aoc/session`, and the server from `AOC_URL` if set.
+    pub fn from_env() -> Option<Self> {
+        Some(Self::with_base_url(
+            base_url_from_env(),
+            session_from_env()?,
+        ))
+    }
+}
+
+impl Fetch for HttpFetcher {
+    fn fetch(&self, day: u8) -> Result<String, InputError> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => {
+                let message = response.into_string().unwrap_or_default();
+                let message = message.lines().next().unwrap_or_default().to_string();
+                Err(InputError::Http { status, message })
+            }
+            Err(err) => Err(InputError::Transport(err.to_string())),
+        }
+    }
+}
+
+/// The session cookie from `AOC_SESSION`, or else `~/.config/aoc/session`.
+pub fn session_from_env() -> Option<String> {
+    let session = env::var("AOC_SESSION")
+        .ok()
+        .or_else(|| fs::read_to_string(session_path()?).ok())?;
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/// The site to talk to, `AOC_URL` if set so a stand-in can be used.
+pub fn base_url_from_env() -> String {
+    env::var("AOC_URL").unwrap_or_else(|_| BASE_URL.to_string())
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME").map(PathBuf::from)
+}
+
+fn session_path() -> Option<PathBuf> {
+    Some(home_dir()?.join(".config/aoc/session"))
+}
+
+/// Resolves each day's input from a cache directory, downloading and caching
+/// it with the fetcher when it's missing.
+pub struct InputManager<F = HttpFetcher> {
+    cache_dir: PathBuf,
+    fetcher: Option<F>,
+}
+
+impl<F: Fetch> InputManager<F> {
+    pub fn new(cache_dir: impl Into<PathBuf>, fetcher: Option<F>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            fetcher,
+        }
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day_{}.txt", day))
+    }
+
+    /// The day's input, from the cache if it's there and otherwise fetched
+    /// and then cached.
+    pub fn get(&self, day: u8) -> Result<String, InputError> {
+        let path = self.cache_path(day);
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err.into()),
+            Err(_) => (),
+        }
+
+        let fetcher = self.fetcher.as_ref().ok_or(InputError::NoSession)?;
+        let input = fetcher.fetch(day)?;
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&path, &input)?;
+        Ok(input)
+    }
+}
+
+impl InputManager {
+    /// The manager for `~/.cache/aoc/2023`, or `$AOC_CACHE_DIR` if set,
+    /// fetching with [`HttpFetcher::from_env`].
+    pub fn from_env() -> Self {
+        let cache_dir = env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or_else(|| Some(home_dir()?.join(".cache/aoc").join(YEAR.to_string())))
+            .unwrap_or_else(|| PathBuf::from(".aoc-cache"));
+        Self::new(cache_dir, HttpFetcher::from_env())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::*;
+    use crate::stand_in::StandIn;
+    use std::cell::Cell;
+
+    const INPUT: &str = "0 3 6 9 12 15\n";
+
+    /// A fresh, empty directory for one test's cache.
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    struct CountingFetcher(Cell<usize>);
+
+    impl Fetch for CountingFetcher {
+        fn fetch(&self, _day: u8) -> Result<String, InputError> {
+            self.0.set(self.0.get() + 1);
+            Ok(INPUT.to_string())
+        }
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let server = StandIn::serve(vec![(200, INPUT.to_string())]);
+        let dir = cache_dir("fetch");
+        let fetcher = HttpFetcher::with_base_url(server.url(), "abc123");
+        let manager = InputManager::new(&dir, Some(fetcher));
+
+        assert_eq!(manager.get(9).unwrap(), INPUT);
+        let request = &server.requests()[0];
+        assert_eq!(
+            (request.method.as_str(), request.path.as_str()),
+            ("GET", "/2023/day/9/input")
+        );
+        assert_eq!(request.cookie.as_deref(), Some("session=abc123"));
+        assert_eq!(request.user_agent.as_deref(), Some(USER_AGENT));
+        assert_eq!(fs::read_to_string(dir.join("day_9.txt")).unwrap(), INPUT);
+
+        // the stand-in only answers once, so this must come from the cache
+        assert_eq!(manager.get(9).unwrap(), INPUT);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_cache_hit() {
+        let dir = cache_dir("hit");
+        let manager = InputManager::new(&dir, Some(CountingFetcher(Cell::new(0))));
+        manager.get(1).unwrap();
+        manager.get(1).unwrap();
+        manager.get(2).unwrap();
+        assert_eq!(manager.fetcher.as_ref().unwrap().0.get(), 2);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_errors() {
+        let server = StandIn::serve(vec![(400, "Please log in.\n".to_string())]);
+        let dir = cache_dir("errors");
+        let fetcher = HttpFetcher::with_base_url(server.url(), "expired");
+        let manager = InputManager::new(&dir, Some(fetcher));
+        let err = manager.get(3).unwrap_err();
+        server.requests();
+        assert_eq!(err.to_string(), "HTTP 400: Please log in.");
+        assert!(!manager.cache_path(3).exists());
+
+        let manager = InputManager::<HttpFetcher>::new(&dir, None);
+        assert!(matches!(manager.get(3), Err(InputError::NoSession)));
+    }
+}
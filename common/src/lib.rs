@@ -0,0 +1,67 @@
+use std::fmt::{self, Display, Formatter};
+
+pub mod input;
+mod parse;
+pub mod run;
+pub mod stand_in;
+
+pub use parse::{parse_lines, parse_number, parse_numbers, ParseError, ParseErrorKind};
+
+/// A day's puzzle, parsed once from the raw input and then solved for both
+/// parts.
+pub trait Solution: Sized {
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    /// Parses the raw puzzle input into the day's model.
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    fn part_one(&self) -> Self::PartOne;
+
+    fn part_two(&self) -> Self::PartTwo;
+}
+
+/// How an answer that couldn't be found is shown.
+pub const NO_ANSWER: &str = "no answer";
+
+/// The answer to a part whose input might have none, shown as [`NO_ANSWER`]
+/// when it doesn't, rather than as some number that could be mistaken for
+/// one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaybeAnswer<T>(pub Option<T>);
+
+impl<T> From<Option<T>> for MaybeAnswer<T> {
+    fn from(answer: Option<T>) -> Self {
+        Self(answer)
+    }
+}
+
+impl<T: Display> Display for MaybeAnswer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(answer) => write!(f, "{}", answer),
+            None => write!(f, "{}", NO_ANSWER),
+        }
+    }
+}
+
+/// A parsed [`Solution`] with its answer types erased, so every day can be
+/// driven through the same interface.
+pub trait DynSolution {
+    /// The answer to `part`, 1 or 2, as text.
+    fn answer(&self, part: u8) -> String;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn answer(&self, part: u8) -> String {
+        match part {
+            1 => self.part_one().to_string(),
+            _ => self.part_two().to_string(),
+        }
+    }
+}
+
+/// Parses `input` as `S`, boxed as a [`DynSolution`].
+pub fn parse_dyn<S: Solution + 'static>(input: &str) -> Result<Box<dyn DynSolution>, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
@@ -0,0 +1,204 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The token should have been a number.
+    InvalidNumber,
+    /// The token isn't one of the values allowed at this point.
+    Unexpected { expected: String },
+    /// Something required was missing, the token being whatever came before.
+    Missing { expected: String },
+}
+
+/// Where and why a puzzle input failed to parse. Lines and columns count from
+/// one, columns in characters rather than bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub kind: ParseErrorKind,
+}
+
+/// The one-based line and column of the byte `offset` into `source`.
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// The byte offset of `slice` into `source`, or the end of `source` if
+/// `slice` wasn't taken from it.
+fn offset_of(source: &str, slice: &str) -> usize {
+    let start = source.as_ptr() as usize;
+    let offset = (slice.as_ptr() as usize).wrapping_sub(start);
+    if offset <= source.len() && source.is_char_boundary(offset) {
+        offset
+    } else {
+        source.len()
+    }
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: impl Into<String>, kind: ParseErrorKind) -> Self {
+        Self {
+            line,
+            column,
+            token: token.into(),
+            kind,
+        }
+    }
+
+    /// An error for `token`, which must be a slice of `source`; its line and
+    /// column are worked out from where it sits in `source`.
+    pub fn at(source: &str, token: &str, kind: ParseErrorKind) -> Self {
+        let (line, column) = line_and_column(source, offset_of(source, token));
+        Self::new(line, column, token, kind)
+    }
+
+    /// `token`, a slice of `source`, isn't one of the `expected` values.
+    pub fn unexpected(source: &str, token: &str, expected: impl Into<String>) -> Self {
+        let expected = expected.into();
+        Self::at(source, token, ParseErrorKind::Unexpected { expected })
+    }
+
+    /// `expected` should have followed `after`, a slice of `source`.
+    pub fn missing(source: &str, after: &str, expected: impl Into<String>) -> Self {
+        let expected = expected.into();
+        let offset = offset_of(source, after) + after.len();
+        let (line, column) = line_and_column(source, offset.min(source.len()));
+        Self::new(line, column, after, ParseErrorKind::Missing { expected })
+    }
+
+    /// Renders the error the way a compiler would: `name`, line and column,
+    /// then the offending line of `input` with the token underlined.
+    pub fn report(&self, name: impl Display, input: &str) -> String {
+        let line = input
+            .lines()
+            .nth(self.line.saturating_sub(1))
+            .unwrap_or_default();
+        let underline = match self.kind {
+            ParseErrorKind::Missing { .. } => 1,
+            _ => self.token.chars().count().max(1),
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+        format!(
+            "{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            name,
+            self,
+            gutter,
+            self.line,
+            line,
+            gutter,
+            " ".repeat(self.column.saturating_sub(1)),
+            "^".repeat(underline)
+        )
+    }
+
+    /// Moves an error found while parsing `section`, a slice of `source`, so
+    /// its line and column are relative to `source` instead.
+    pub fn within(mut self, source: &str, section: &str) -> Self {
+        let (line, column) = line_and_column(source, offset_of(source, section));
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            ParseErrorKind::InvalidNumber => write!(f, "expected a number, found `{}`", self.token),
+            ParseErrorKind::Unexpected { expected } => {
+                write!(f, "expected {}, found `{}`", expected, self.token)
+            }
+            ParseErrorKind::Missing { expected } => write!(f, "expected {}", expected),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `source`, as a number.
+pub fn parse_number<T: FromStr>(source: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(source, token, ParseErrorKind::InvalidNumber))
+}
+
+/// Parses every whitespace separated number in `tokens`, a slice of `source`.
+pub fn parse_numbers<T: FromStr>(source: &str, tokens: &str) -> Result<Vec<T>, ParseError> {
+    tokens
+        .split_whitespace()
+        .map(|token| parse_number(source, token))
+        .collect()
+}
+
+/// Parses every line of `input` with `parse_line`, reporting errors relative
+/// to `input` rather than the line.
+pub fn parse_lines<T, F>(input: &str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .map(|line| parse_line(line).map_err(|err| err.within(input, line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "1 2 3
+4 five 6";
+
+    #[test]
+    fn test_location() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let err = parse_numbers::<u32>(line, line).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        let err = err.within(INPUT, line);
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.to_string(), "2:3: expected a number, found `five`");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let err = parse_lines(INPUT, |line| parse_numbers::<u32>(line, line)).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 3, "five", ParseErrorKind::InvalidNumber)
+        );
+        let numbers = parse_lines("1 2\n3", |line| parse_numbers::<u32>(line, line));
+        assert_eq!(numbers, Ok(vec![vec![1, 2], vec![3]]));
+    }
+
+    #[test]
+    fn test_missing() {
+        let (first, _) = INPUT.split_once(' ').unwrap();
+        let err = ParseError::missing(INPUT, INPUT, "a blank line");
+        assert_eq!((err.line, err.column), (2, 9));
+        let err = ParseError::missing(INPUT, first, "`:`");
+        assert_eq!(err.to_string(), "1:2: expected `:`");
+    }
+
+    #[test]
+    fn test_report() {
+        let err = parse_lines(INPUT, |line| parse_numbers::<u32>(line, line)).unwrap_err();
+        assert_eq!(
+            err.report("input.txt", INPUT),
+            "input.txt:2:3: expected a number, found `five`
+  |
+2 | 4 five 6
+  |   ^^^^"
+        );
+    }
+}
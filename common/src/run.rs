@@ -0,0 +1,230 @@
+use crate::input::{InputError, InputManager};
+use crate::{DynSolution, ParseError, Solution};
+use std::convert::Infallible;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::{env, fs};
+
+/// Where a puzzle input is read from: a file, stdin when given as `-`, or a
+/// day's own input through the [`InputManager`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    Puzzle(u8),
+}
+
+impl Source {
+    /// A command line argument as a source, `-` meaning stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => Ok(fs::read_to_string(path)?),
+            Source::Puzzle(day) => InputManager::from_env().get(*day),
+        }
+    }
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Infallible> {
+        Ok(Source::from_arg(s))
+    }
+}
+
+impl From<PathBuf> for Source {
+    fn from(path: PathBuf) -> Self {
+        Source::File(path)
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Puzzle(day) => write!(f, "day {}", day),
+        }
+    }
+}
+
+/// Parses `input` as `S` and solves the requested parts, in order.
+pub fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Vec<String>, ParseError> {
+    let solution = S::parse(input)?;
+    Ok(parts.iter().map(|&part| solution.answer(part)).collect())
+}
+
+/// The answers for each input, one row per input and one column per part.
+pub struct AnswerTable {
+    parts: Vec<u8>,
+    rows: Vec<(String, Vec<String>)>,
+}
+
+impl AnswerTable {
+    pub fn new(parts: &[u8]) -> Self {
+        Self {
+            parts: parts.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, input: impl Display, answers: Vec<String>) {
+        self.rows.push((input.to_string(), answers));
+    }
+
+    /// Adds a row for an input that couldn't be solved.
+    pub fn push_failed(&mut self, input: impl Display) {
+        let answers = vec!["error".to_string(); self.parts.len()];
+        self.push(input, answers);
+    }
+}
+
+impl Display for AnswerTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let header = std::iter::once("Input".to_string())
+            .chain(self.parts.iter().map(|part| format!("Part {}", part)))
+            .collect::<Vec<_>>();
+        let rows = self
+            .rows
+            .iter()
+            .map(|(input, answers)| std::iter::once(input).chain(answers).collect::<Vec<_>>());
+
+        let mut widths = header.iter().map(String::len).collect::<Vec<_>>();
+        for row in rows.clone() {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let header = header.iter().collect::<Vec<_>>();
+        for (i, row) in std::iter::once(header).chain(rows).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let cells = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>();
+            write!(f, "{}", cells.join("  ").trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+const USAGE: &str = "usage: [--input] <FILE>... (`-` reads stdin, default the cached puzzle input)";
+
+/// The inputs named on the command line: `--input <file>` or bare paths, in
+/// order, or else `day`'s puzzle input.
+fn sources_from_args(
+    day: u8,
+    mut args: impl Iterator<Item = String>,
+) -> Result<Vec<Source>, String> {
+    let mut sources = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => {
+                let path = args.next().ok_or("--input needs a file")?;
+                sources.push(Source::from_arg(&path));
+            }
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            flag if flag.starts_with('-') && flag != "-" => {
+                return Err(format!("unknown option {}\n{}", flag, USAGE))
+            }
+            path => sources.push(Source::from_arg(path)),
+        }
+    }
+    if sources.is_empty() {
+        sources.push(Source::Puzzle(day));
+    }
+    Ok(sources)
+}
+
+/// Entry point shared by every day's binary: solves each input named on the
+/// command line in turn, or the day's puzzle input, and prints a table of
+/// answers.
+pub fn main<S: Solution>(day: u8) -> ExitCode {
+    let sources = match sources_from_args(day, env::args().skip(1)) {
+        Ok(sources) => sources,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = [1, 2];
+    let mut table = AnswerTable::new(&parts);
+    let mut failed = false;
+    for source in &sources {
+        let solved = source
+            .read()
+            .map_err(|err| format!("{}: {}", source, err))
+            .and_then(|input| solve::<S>(&input, &parts).map_err(|err| err.report(source, &input)));
+        match solved {
+            Ok(answers) => table.push(source, answers),
+            Err(message) => {
+                eprintln!("error: {}", message);
+                table.push_failed(source);
+                failed = true;
+            }
+        }
+    }
+
+    println!("{}", table);
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run::*;
+
+    #[test]
+    fn test_sources() {
+        let args = ["a.txt", "-", "--input", "b.txt"].map(String::from);
+        assert_eq!(
+            sources_from_args(1, args.into_iter()),
+            Ok(vec![
+                Source::File(PathBuf::from("a.txt")),
+                Source::Stdin,
+                Source::File(PathBuf::from("b.txt"))
+            ])
+        );
+        assert_eq!(
+            sources_from_args(5, std::iter::empty()),
+            Ok(vec![Source::Puzzle(5)])
+        );
+        assert!(sources_from_args(1, ["--part".to_string()].into_iter()).is_err());
+    }
+
+    #[test]
+    fn test_table() {
+        let mut table = AnswerTable::new(&[1, 2]);
+        table.push("example.txt", vec!["142".to_string(), "281".to_string()]);
+        table.push_failed(Source::Stdin);
+        assert_eq!(
+            table.to_string(),
+            "Input        Part 1  Part 2
+example.txt  142     281
+<stdin>      error   error"
+        );
+    }
+}
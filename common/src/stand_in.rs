@@ -0,0 +1,110 @@
+//! A minimal HTTP server standing in for the Advent of Code site, so the
+//! clients that download inputs and submit answers can be tested offline.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread::{self, JoinHandle};
+
+/// A request as the stand-in received it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub user_agent: Option<String>,
+    pub body: String,
+}
+
+pub struct StandIn {
+    url: String,
+    handle: JoinHandle<Vec<Request>>,
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        500 => "Internal Server Error",
+        _ => "Status",
+    }
+}
+
+fn read_request(stream: &TcpStream) -> io::Result<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut cookie = None;
+    let mut user_agent = None;
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        let Some((name, value)) = header.trim().split_once(": ") else {
+            break;
+        };
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = Some(value.to_string()),
+            "user-agent" => user_agent = Some(value.to_string()),
+            "content-length" => length = value.parse().unwrap_or(0),
+            _ => (),
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    let body = String::from_utf8_lossy(&body).into_owned();
+
+    Ok(Request {
+        method,
+        path,
+        cookie,
+        user_agent,
+        body,
+    })
+}
+
+impl StandIn {
+    /// Serves each `(status, body)` response to one request, in order, and
+    /// then stops listening.
+    pub fn serve(responses: Vec<(u16, String)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind stand-in server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().expect("Failed to accept request");
+                    let request = read_request(&stream).expect("Failed to read request");
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        reason(status),
+                        body.len(),
+                        body
+                    )
+                    .expect("Failed to write response");
+                    request
+                })
+                .collect()
+        });
+        Self { url, handle }
+    }
+
+    /// The base URL to point a client at.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Waits until every response has been served, returning the requests
+    /// that were received.
+    pub fn requests(self) -> Vec<Request> {
+        self.handle.join().expect("Stand-in server panicked")
+    }
+}
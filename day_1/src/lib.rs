@@ -0,0 +1,86 @@
+//! Day 1: Trebuchet?!
+
+use common::{ParseError, Solution};
+use std::str::CharIndices;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Yields the digits of a calibration line from left to right, optionally
+/// including digits spelled out as words (`one` through `nine`).
+pub struct DigitIter<'a> {
+    line: &'a str,
+    chars: CharIndices<'a>,
+    words: bool,
+}
+
+impl<'a> DigitIter<'a> {
+    pub fn new(line: &'a str, words: bool) -> Self {
+        Self {
+            line,
+            chars: line.char_indices(),
+            words,
+        }
+    }
+}
+
+impl Iterator for DigitIter<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, ch) in self.chars.by_ref() {
+            if let Some(digit) = ch.to_digit(10) {
+                return Some(digit);
+            }
+            if !self.words {
+                continue;
+            }
+            for (digit, word) in WORDS.iter().enumerate() {
+                if self.line[index..].starts_with(word) {
+                    return Some((digit + 1) as u32);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The amended calibration document, one line per calibration value.
+pub struct CalibrationDocument {
+    pub lines: Vec<String>,
+}
+
+impl CalibrationDocument {
+    /// Sums the two-digit values made from the first and last digit of every
+    /// line.
+    pub fn calibration_sum(&self, words: bool) -> u32 {
+        self.lines
+            .iter()
+            .filter_map(|line| {
+                let mut digit_iter = DigitIter::new(line, words);
+                let first = digit_iter.next()?;
+                let last = digit_iter.last().unwrap_or(first);
+                Some(first * 10 + last)
+            })
+            .sum()
+    }
+}
+
+impl Solution for CalibrationDocument {
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let lines = input.lines().map(String::from).collect();
+        Ok(Self { lines })
+    }
+
+    fn part_one(&self) -> u32 {
+        self.calibration_sum(false)
+    }
+
+    fn part_two(&self) -> u32 {
+        self.calibration_sum(true)
+    }
+}
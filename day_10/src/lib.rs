@@ -0,0 +1,206 @@
+//! Day 10: Pipe Maze
+
+use common::{ParseError, Solution};
+use geometry::Direction;
+pub use geometry::{Area, Interior};
+use grid::Grid;
+
+/// A position on the map, `y` increasing southwards.
+pub type Point = geometry::Point<i32>;
+
+/// A tile on the map: a pipe segment named after the directions it connects,
+/// the start, or ground.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Tile {
+    PipeNS,
+    PipeEW,
+    BendNE,
+    BendNW,
+    BendSE,
+    BendSW,
+    Start,
+    Ground,
+}
+
+impl Tile {
+    /// The two directions the pipe connects, if it is a pipe.
+    pub fn connections(&self) -> Option<[Direction; 2]> {
+        match self {
+            Tile::BendNE => Some([Direction::North, Direction::East]),
+            Tile::BendNW => Some([Direction::North, Direction::West]),
+            Tile::BendSE => Some([Direction::South, Direction::East]),
+            Tile::BendSW => Some([Direction::South, Direction::West]),
+            Tile::PipeEW => Some([Direction::East, Direction::West]),
+            Tile::PipeNS => Some([Direction::North, Direction::South]),
+            Tile::Start | Tile::Ground => None,
+        }
+    }
+
+    /// Whether the pipe connects in `direction`.
+    pub fn connects(&self, direction: Direction) -> bool {
+        self.connections()
+            .is_some_and(|connections| connections.contains(&direction))
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            'L' => Ok(Tile::BendNE),
+            'J' => Ok(Tile::BendNW),
+            'F' => Ok(Tile::BendSE),
+            '7' => Ok(Tile::BendSW),
+            '-' => Ok(Tile::PipeEW),
+            '|' => Ok(Tile::PipeNS),
+            'S' => Ok(Tile::Start),
+            '.' => Ok(Tile::Ground),
+            _ => Err(c),
+        }
+    }
+}
+
+/// Every tile on the map, and where the animal starts.
+pub struct PipeMap {
+    pub pipes: Grid<Tile>,
+    pub start: Point,
+}
+
+impl PipeMap {
+    /// The tile at `point`, if it is on the map.
+    pub fn tile(&self, point: Point) -> Option<&Tile> {
+        self.pipes.get(point.try_cast()?)
+    }
+
+    /// The directions from the start whose neighbour connects back to it.
+    pub fn start_directions(&self) -> Vec<Direction> {
+        Direction::ALL
+            .into_iter()
+            .filter(|&direction| {
+                self.tile(self.start.step(direction))
+                    .is_some_and(|tile| tile.connects(direction.opposite()))
+            })
+            .collect()
+    }
+
+    /// The direction to move from `current` to continue along the pipe,
+    /// given the direction of the last move, or `None` if the pipe there
+    /// doesn't connect back the way it was entered.
+    pub fn find_next(&self, current: Point, last: Direction) -> Option<Direction> {
+        let [a, b] = self.tile(current)?.connections()?;
+        match last.opposite() {
+            came_from if came_from == a => Some(b),
+            came_from if came_from == b => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Follows the loop from the start until it returns, yielding every point
+    /// visited, or `None` if the start isn't joined to exactly two pipes or
+    /// the loop breaks. The start appears at both ends.
+    pub fn walk_loop(&self) -> Option<Vec<Point>> {
+        let [mut direction, _] = self.start_directions()[..] else {
+            return None;
+        };
+        let mut current = self.start;
+        let mut visited: Vec<Point> = vec![self.start];
+
+        loop {
+            current = current.step(direction);
+            visited.push(current);
+            if current == self.start {
+                return Some(visited);
+            }
+            direction = self.find_next(current, direction)?;
+        }
+    }
+}
+
+impl Solution for PipeMap {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let pipes = Grid::<Tile>::parse(input)?;
+        let start = pipes
+            .position(|tile| *tile == Tile::Start)
+            .and_then(|start| start.try_cast())
+            .ok_or_else(|| ParseError::missing(input, input, "a start tile `S`"))?;
+        let map = Self { pipes, start };
+
+        if map.walk_loop().is_none() {
+            let at = input.find('S').unwrap_or_default();
+            return Err(ParseError::unexpected(
+                input,
+                &input[at..at + 1],
+                "a start tile on a closed loop of pipes",
+            ));
+        }
+        Ok(map)
+    }
+
+    fn part_one(&self) -> usize {
+        let visited = self.walk_loop().expect("parse checks the loop closes");
+        (visited.len() - 1) / 2
+    }
+
+    fn part_two(&self) -> usize {
+        let visited = self.walk_loop().expect("parse checks the loop closes");
+        visited.shoestring().picks(visited.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PipeMap;
+    use common::Solution;
+
+    const INPUT_PART_1: &str = "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+    const INPUT_PART_2: &str = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    #[test]
+    fn test_part_1() {
+        let result = PipeMap::parse(INPUT_PART_1).unwrap().part_one();
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = PipeMap::parse(INPUT_PART_2).unwrap().part_two();
+        assert_eq!(result, 10);
+        let res2 = PipeMap::parse(INPUT_PART_1).unwrap().part_two();
+        assert_eq!(res2, 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT_PART_1.replace("SJ", "S.");
+        let err = PipeMap::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "3:1: expected a start tile on a closed loop of pipes, found `S`"
+        );
+
+        let input = INPUT_PART_1.replace(".FJ", ".F-");
+        let err = PipeMap::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "3:1: expected a start tile on a closed loop of pipes, found `S`"
+        );
+    }
+}
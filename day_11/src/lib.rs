@@ -0,0 +1,127 @@
+//! Day 11: Cosmic Expansion
+
+use common::{ParseError, Solution};
+use grid::Grid;
+
+/// A position in the image.
+pub type Point = geometry::Point<usize>;
+
+/// Galaxy positions after expansion.
+pub type Universe = Vec<Point>;
+
+/// The telescope image: where the galaxies are, and which rows and columns
+/// are empty and will expand.
+pub struct Image {
+    pub galaxies: Vec<Point>,
+    pub empty_rows: Vec<usize>,
+    pub empty_cols: Vec<usize>,
+}
+
+impl Image {
+    /// The galaxy positions once every empty row and column is replaced by
+    /// `expand` of them.
+    pub fn expand(&self, expand: usize) -> Universe {
+        self.galaxies
+            .iter()
+            .map(|galaxy| {
+                let num_empty_cols = self
+                    .empty_cols
+                    .iter()
+                    .take_while(|ec| **ec <= galaxy.x)
+                    .count();
+                let num_empty_rows = self
+                    .empty_rows
+                    .iter()
+                    .take_while(|er| **er <= galaxy.y)
+                    .count();
+                Point::new(
+                    galaxy.x + num_empty_cols * (expand - 1),
+                    galaxy.y + num_empty_rows * (expand - 1),
+                )
+            })
+            .collect()
+    }
+
+    /// Sum of the shortest paths between every pair of galaxies.
+    pub fn all_distances(&self, expand: usize) -> usize {
+        let universe = self.expand(expand);
+        let mut total = 0;
+        for from_idx in 0..universe.len() {
+            for to_idx in from_idx + 1..universe.len() {
+                let from = universe[from_idx];
+                let to = universe[to_idx];
+                total += from.manhattan(&to);
+            }
+        }
+        total
+    }
+}
+
+impl Solution for Image {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let image = Grid::parse_with(input, |c| matches!(c, '.' | '#').then_some(c))?;
+        let empty_rows = image
+            .rows()
+            .enumerate()
+            .filter_map(|(y, row)| (!row.contains(&'#')).then_some(y))
+            .collect();
+        let empty_cols = image
+            .columns()
+            .enumerate()
+            .filter_map(|(x, mut col)| (!col.any(|c| *c == '#')).then_some(x))
+            .collect();
+        let galaxies = image
+            .iter()
+            .filter(|(_, c)| **c == '#')
+            .map(|(pos, _)| pos)
+            .collect();
+
+        Ok(Self {
+            galaxies,
+            empty_rows,
+            empty_cols,
+        })
+    }
+
+    fn part_one(&self) -> usize {
+        self.all_distances(2)
+    }
+
+    fn part_two(&self) -> usize {
+        self.all_distances(1_000_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Image;
+    use common::Solution;
+
+    const INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn test_part_1() {
+        let result = Image::parse(INPUT).unwrap().all_distances(2);
+        assert_eq!(result, 374);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Image::parse(INPUT).unwrap().all_distances(10);
+        assert_eq!(result, 1030);
+        let result = Image::parse(INPUT).unwrap().all_distances(100);
+        assert_eq!(result, 8410);
+    }
+}
@@ -0,0 +1,183 @@
+//! Day 12: Hot Springs
+
+use common::{parse_lines, parse_number, ParseError, Solution};
+
+/// The condition of one spring, as far as the records know.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Spring {
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Spring::Operational),
+            '#' => Some(Spring::Damaged),
+            '?' => Some(Spring::Unknown),
+            _ => None,
+        }
+    }
+}
+
+/// One row of the records: the springs, and the sizes of each contiguous
+/// group of damaged springs.
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub springs: Vec<Spring>,
+    pub groups: Vec<usize>,
+}
+
+impl Record {
+    /// Parses a line like `???.### 1,1,3`.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (springs_str, groups_str) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::missing(line, line, "a space before the groups"))?;
+
+        let springs = springs_str
+            .char_indices()
+            .map(|(i, c)| {
+                Spring::from_char(c).ok_or_else(|| {
+                    ParseError::unexpected(
+                        line,
+                        &springs_str[i..i + c.len_utf8()],
+                        "`.`, `#` or `?`",
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let groups = groups_str
+            .split(',')
+            .map(|token| parse_number(line, token))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { springs, groups })
+    }
+
+    /// The record with its springs repeated `times` times, separated by
+    /// unknown springs, and its groups repeated to match.
+    pub fn unfold(&self, times: usize) -> Self {
+        let mut springs = Vec::with_capacity((self.springs.len() + 1) * times);
+        for i in 0..times {
+            if i > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+        Self {
+            springs,
+            groups: self.groups.repeat(times),
+        }
+    }
+
+    /// How many ways the unknown springs can be filled in to match the
+    /// groups.
+    pub fn arrangements(&self) -> u64 {
+        let mut memo = vec![vec![None; self.groups.len() + 1]; self.springs.len() + 1];
+        self.count(0, 0, &mut memo)
+    }
+
+    /// Arrangements of the springs from `spring` on that match the groups
+    /// from `group` on, memoised on both.
+    fn count(&self, spring: usize, group: usize, memo: &mut [Vec<Option<u64>>]) -> u64 {
+        if let Some(count) = memo[spring][group] {
+            return count;
+        }
+        let springs = &self.springs[spring..];
+
+        let count = match springs.first() {
+            None => u64::from(group == self.groups.len()),
+            Some(first) => {
+                let mut count = 0;
+                if *first != Spring::Damaged {
+                    count += self.count(spring + 1, group, memo);
+                }
+                if *first != Spring::Operational && group < self.groups.len() {
+                    // the group starts here, so it must fit and be followed by
+                    // the end or a spring that can be operational
+                    let size = self.groups[group];
+                    let fits = size <= springs.len()
+                        && springs[..size].iter().all(|s| *s != Spring::Operational)
+                        && springs.get(size) != Some(&Spring::Damaged);
+                    if fits {
+                        let next = (spring + size + 1).min(self.springs.len());
+                        count += self.count(next, group + 1, memo);
+                    }
+                }
+                count
+            }
+        };
+
+        memo[spring][group] = Some(count);
+        count
+    }
+}
+
+/// The damaged condition records.
+pub struct Records {
+    pub records: Vec<Record>,
+}
+
+impl Solution for Records {
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let records = parse_lines(input, Record::parse)?;
+        Ok(Self { records })
+    }
+
+    fn part_one(&self) -> u64 {
+        self.records.iter().map(Record::arrangements).sum()
+    }
+
+    fn part_two(&self) -> u64 {
+        self.records
+            .iter()
+            .map(|record| record.unfold(5).arrangements())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+    #[test]
+    fn test_part_1() {
+        let result = Records::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Records::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 525152);
+    }
+
+    #[test]
+    fn test_arrangements() {
+        let records = Records::parse(INPUT).unwrap();
+        let counts = records
+            .records
+            .iter()
+            .map(|record| record.unfold(5).arrangements())
+            .collect::<Vec<_>>();
+        assert_eq!(counts, [1, 16384, 1, 16, 2500, 506250]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Records::parse("???.### 1,1,3\n.?x. 1").err().unwrap();
+        assert_eq!(err.to_string(), "2:3: expected `.`, `#` or `?`, found `x`");
+    }
+}
@@ -0,0 +1,6 @@
+use day_12::Records;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<Records>(12)
+}
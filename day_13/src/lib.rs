@@ -0,0 +1,121 @@
+//! Day 13: Point of Incidence
+
+use common::{ParseError, Solution};
+use grid::Grid;
+
+/// The number of rows above a horizontal line of reflection, if any. With
+/// `smudge`, the reflection must be off by exactly one cell.
+pub fn horizontal_reflection_index(pattern: &Grid<char>, smudge: bool) -> Option<usize> {
+    let expected = usize::from(smudge);
+    (1..pattern.height()).find(|&split| {
+        let size = split.min(pattern.height() - split);
+        let differences = (0..size)
+            .map(|i| {
+                pattern
+                    .row(split - 1 - i)
+                    .iter()
+                    .zip(pattern.row(split + i))
+                    .filter(|(above, below)| above != below)
+                    .count()
+            })
+            .sum::<usize>();
+        differences == expected
+    })
+}
+
+/// The number of columns left of a vertical line of reflection, if any.
+pub fn vertical_reflection_index(pattern: &Grid<char>, smudge: bool) -> Option<usize> {
+    horizontal_reflection_index(&pattern.transpose(), smudge)
+}
+
+/// The patterns of ash and rocks from the notes.
+pub struct Notes {
+    pub patterns: Vec<Grid<char>>,
+}
+
+impl Notes {
+    /// Sum of the reflection lines of every pattern: columns to the left,
+    /// plus 100 times the rows above.
+    pub fn summarize(&self, smudge: bool) -> usize {
+        self.patterns
+            .iter()
+            .map(
+                |pattern| match horizontal_reflection_index(pattern, smudge) {
+                    Some(row) => 100 * row,
+                    None => vertical_reflection_index(pattern, smudge).unwrap_or(0),
+                },
+            )
+            .sum()
+    }
+}
+
+impl Solution for Notes {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let patterns = input
+            .split("\n\n")
+            .map(|pattern| {
+                Grid::parse_with(pattern, |c| matches!(c, '.' | '#').then_some(c))
+                    .map_err(|err| err.within(input, pattern))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { patterns })
+    }
+
+    fn part_one(&self) -> usize {
+        self.summarize(false)
+    }
+
+    fn part_two(&self) -> usize {
+        self.summarize(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn test_part_1() {
+        let result = Notes::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 405);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Notes::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 400);
+    }
+
+    #[test]
+    fn test_smudged_reflection() {
+        let notes = Notes::parse(INPUT).unwrap();
+        let [first, second] = &notes.patterns[..] else {
+            panic!("expected two patterns");
+        };
+        // fixing the smudge moves each reflection, rather than keeping the
+        // one that was already there
+        assert_eq!(vertical_reflection_index(first, false), Some(5));
+        assert_eq!(horizontal_reflection_index(first, true), Some(3));
+        assert_eq!(horizontal_reflection_index(second, false), Some(4));
+        assert_eq!(horizontal_reflection_index(second, true), Some(1));
+    }
+}
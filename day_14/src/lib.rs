@@ -0,0 +1,212 @@
+//! Day 14: Parabolic Reflector Dish
+
+use common::{ParseError, Solution};
+use geometry::{Direction, Point};
+use grid::Grid;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+/// The number of spin cycles part two runs.
+pub const CYCLES: usize = 1_000_000_000;
+
+/// One cell of the platform.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rock {
+    /// A rounded rock, which rolls when the platform is tilted.
+    Round,
+    /// A cube-shaped rock, which stays put.
+    Cube,
+    Empty,
+}
+
+impl TryFrom<char> for Rock {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            'O' => Ok(Rock::Round),
+            '#' => Ok(Rock::Cube),
+            '.' => Ok(Rock::Empty),
+            _ => Err(c),
+        }
+    }
+}
+
+impl Display for Rock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Rock::Round => 'O',
+            Rock::Cube => '#',
+            Rock::Empty => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+/// The platform of rocks attached to the reflector dish.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Platform {
+    pub grid: Grid<Rock>,
+}
+
+impl Platform {
+    /// Tilts the platform so every rounded rock rolls as far as it can in
+    /// `direction`.
+    pub fn tilt(&mut self, direction: Direction) {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        // the cells along the edge the rocks roll towards
+        let edge = match direction {
+            Direction::North => (0..width).map(|x| Point::new(x, 0)).collect::<Vec<_>>(),
+            Direction::South => (0..width).map(|x| Point::new(x, height - 1)).collect(),
+            Direction::West => (0..height).map(|y| Point::new(0, y)).collect(),
+            Direction::East => (0..height).map(|y| Point::new(width - 1, y)).collect(),
+        };
+        let back = direction.opposite();
+
+        for start in edge {
+            // where the next rounded rock in this lane comes to rest
+            let mut free = Some(start);
+            let mut pos = Some(start);
+            while let Some(current) = pos {
+                match self.grid[current] {
+                    Rock::Cube => free = self.grid.step(current, back),
+                    Rock::Round => {
+                        let rest = free.expect("a rock rolls no further than itself");
+                        self.grid[current] = Rock::Empty;
+                        self.grid[rest] = Rock::Round;
+                        free = self.grid.step(rest, back);
+                    }
+                    Rock::Empty => (),
+                }
+                pos = self.grid.step(current, back);
+            }
+        }
+    }
+
+    /// Tilts north, west, south and then east.
+    pub fn spin_cycle(&mut self) {
+        for direction in [
+            Direction::North,
+            Direction::West,
+            Direction::South,
+            Direction::East,
+        ] {
+            self.tilt(direction);
+        }
+    }
+
+    /// The platform after `cycles` spin cycles, skipping ahead once the
+    /// arrangement starts repeating.
+    pub fn after_spin_cycles(&self, cycles: usize) -> Self {
+        let mut seen = HashMap::new();
+        let mut history = Vec::new();
+        let mut platform = self.clone();
+
+        for cycle in 0..cycles {
+            if let Some(start) = seen.insert(platform.clone(), cycle) {
+                let period = cycle - start;
+                return history.swap_remove(start + (cycles - start) % period);
+            }
+            history.push(platform.clone());
+            platform.spin_cycle();
+        }
+        platform
+    }
+
+    /// The load on the north support beams: each rounded rock counts its
+    /// distance from the south edge, plus one.
+    pub fn load(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|(_, rock)| **rock == Rock::Round)
+            .map(|(pos, _)| self.grid.height() - pos.y)
+            .sum()
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Solution for Platform {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input)?;
+        Ok(Self { grid })
+    }
+
+    fn part_one(&self) -> usize {
+        let mut platform = self.clone();
+        platform.tilt(Direction::North);
+        platform.load()
+    }
+
+    fn part_two(&self) -> usize {
+        self.after_spin_cycles(CYCLES).load()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn test_part_1() {
+        let result = Platform::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 136);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Platform::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 64);
+    }
+
+    #[test]
+    fn test_spin_cycle() {
+        let mut platform = Platform::parse(INPUT).unwrap();
+        platform.spin_cycle();
+        assert_eq!(
+            platform.to_string(),
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#...."
+        );
+        assert_eq!(
+            platform.after_spin_cycles(2),
+            Platform::parse(INPUT).unwrap().after_spin_cycles(3)
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        // a blank line used to parse as a platform with no columns, which
+        // panicked when tilted
+        let err = Platform::parse("\n").err().unwrap();
+        assert_eq!(err.to_string(), "1:1: expected a row of cells");
+        let err = Platform::parse("").err().unwrap();
+        assert_eq!(err.to_string(), "1:1: expected a row of cells");
+    }
+}
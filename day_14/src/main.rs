@@ -0,0 +1,6 @@
+use day_14::Platform;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<Platform>(14)
+}
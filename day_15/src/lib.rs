@@ -0,0 +1,197 @@
+//! Day 15: Lens Library
+
+use common::{ParseError, Solution};
+use std::fmt::{self, Display, Formatter};
+
+/// The Holiday ASCII String Helper algorithm: a hash of `s` from 0 to 255.
+pub fn hash(s: &str) -> u8 {
+    s.bytes().fold(0, |current, byte| {
+        current.wrapping_add(byte).wrapping_mul(17)
+    })
+}
+
+/// What a step does to the box its label hashes to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// `=N`: put in a lens with focal length `N`, replacing any with the
+    /// same label.
+    Insert(u8),
+    /// `-`: take out the lens with the label, if there is one.
+    Remove,
+}
+
+/// One step of the initialization sequence.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub label: String,
+    pub operation: Operation,
+}
+
+impl Step {
+    /// Parses a step like `rn=1` or `cm-`, a slice of `source`.
+    pub fn parse(source: &str, token: &str) -> Result<Self, ParseError> {
+        let (label, operation) = if let Some(label) = token.strip_suffix('-') {
+            (label, Operation::Remove)
+        } else if let Some((label, focal_length)) = token.split_once('=') {
+            let focal_length = match focal_length.as_bytes() {
+                [digit @ b'1'..=b'9'] => digit - b'0',
+                _ => {
+                    return Err(ParseError::unexpected(
+                        source,
+                        focal_length,
+                        "a focal length from 1 to 9",
+                    ))
+                }
+            };
+            (label, Operation::Insert(focal_length))
+        } else {
+            return Err(ParseError::missing(source, token, "`=` or `-`"));
+        };
+
+        if label.is_empty() {
+            return Err(ParseError::missing(source, &token[..0], "a label"));
+        }
+        Ok(Self {
+            label: label.to_string(),
+            operation,
+        })
+    }
+
+    /// The box the step's lens goes in or comes out of.
+    pub fn box_number(&self) -> usize {
+        usize::from(hash(&self.label))
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.operation {
+            Operation::Insert(focal_length) => write!(f, "{}={}", self.label, focal_length),
+            Operation::Remove => write!(f, "{}-", self.label),
+        }
+    }
+}
+
+/// A labelled lens in one of the boxes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lens {
+    pub label: String,
+    pub focal_length: u8,
+}
+
+/// The 256 boxes of lenses, each keeping its lenses in the order they went
+/// in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Boxes {
+    pub boxes: Vec<Vec<Lens>>,
+}
+
+impl Default for Boxes {
+    fn default() -> Self {
+        Self {
+            boxes: vec![Vec::new(); 256],
+        }
+    }
+}
+
+impl Boxes {
+    /// Carries out one step of the HASHMAP procedure.
+    pub fn apply(&mut self, step: &Step) {
+        let lenses = &mut self.boxes[step.box_number()];
+        let position = lenses.iter().position(|lens| lens.label == step.label);
+        match (step.operation, position) {
+            (Operation::Insert(focal_length), Some(i)) => lenses[i].focal_length = focal_length,
+            (Operation::Insert(focal_length), None) => lenses.push(Lens {
+                label: step.label.clone(),
+                focal_length,
+            }),
+            (Operation::Remove, Some(i)) => {
+                lenses.remove(i);
+            }
+            (Operation::Remove, None) => (),
+        }
+    }
+
+    /// The sum over every lens of its box number plus one, times its slot
+    /// plus one, times its focal length.
+    pub fn focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(box_number, lenses)| {
+                lenses.iter().enumerate().map(move |(slot, lens)| {
+                    (box_number + 1) * (slot + 1) * usize::from(lens.focal_length)
+                })
+            })
+            .sum()
+    }
+}
+
+/// The initialization sequence, in order.
+pub struct InitSequence {
+    pub steps: Vec<Step>,
+}
+
+impl Solution for InitSequence {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let steps = input
+            .trim_end()
+            .split(',')
+            .map(|token| Step::parse(input, token))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { steps })
+    }
+
+    fn part_one(&self) -> usize {
+        self.steps
+            .iter()
+            .map(|step| usize::from(hash(&step.to_string())))
+            .sum()
+    }
+
+    fn part_two(&self) -> usize {
+        let mut boxes = Boxes::default();
+        for step in &self.steps {
+            boxes.apply(step);
+        }
+        boxes.focusing_power()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn test_part_1() {
+        let result = InitSequence::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 1320);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = InitSequence::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 145);
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("rn"), 0);
+        assert_eq!(hash("qp"), 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = InitSequence::parse("rn=1,cm=10").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "1:9: expected a focal length from 1 to 9, found `10`"
+        );
+    }
+}
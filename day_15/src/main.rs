@@ -0,0 +1,6 @@
+use day_15::InitSequence;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<InitSequence>(15)
+}
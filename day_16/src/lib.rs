@@ -0,0 +1,173 @@
+//! Day 16: The Floor Will Be Lava
+
+use common::{ParseError, Solution};
+use geometry::{Direction, Point};
+use grid::{Grid, Position};
+use rayon::prelude::*;
+
+/// A tile of the contraption.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    /// `/`
+    MirrorForward,
+    /// `\`
+    MirrorBack,
+    /// `|`
+    SplitterVertical,
+    /// `-`
+    SplitterHorizontal,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Tile::Empty),
+            '/' => Ok(Tile::MirrorForward),
+            '\\' => Ok(Tile::MirrorBack),
+            '|' => Ok(Tile::SplitterVertical),
+            '-' => Ok(Tile::SplitterHorizontal),
+            _ => Err(c),
+        }
+    }
+}
+
+impl Tile {
+    /// The directions a beam travelling in `direction` leaves the tile in: one,
+    /// or a second when a splitter splits it.
+    pub fn outgoing(self, direction: Direction) -> (Direction, Option<Direction>) {
+        let horizontal = matches!(direction, Direction::East | Direction::West);
+        match self {
+            Tile::MirrorForward if horizontal => (direction.turn_left(), None),
+            Tile::MirrorForward => (direction.turn_right(), None),
+            Tile::MirrorBack if horizontal => (direction.turn_right(), None),
+            Tile::MirrorBack => (direction.turn_left(), None),
+            Tile::SplitterVertical if horizontal => (Direction::North, Some(Direction::South)),
+            Tile::SplitterHorizontal if !horizontal => (Direction::East, Some(Direction::West)),
+            _ => (direction, None),
+        }
+    }
+}
+
+/// The layout of mirrors and splitters.
+pub struct Contraption {
+    pub grid: Grid<Tile>,
+}
+
+impl Contraption {
+    /// How many tiles end up energized by a beam entering at `start`,
+    /// travelling in `direction`.
+    pub fn energized(&self, start: Position, direction: Direction) -> usize {
+        // the directions a beam has already passed through each tile in, so
+        // a beam going round a loop stops where it started
+        let mut visited = self.grid.map(|_| 0u8);
+        let mut beams = vec![(start, direction)];
+
+        while let Some((pos, direction)) = beams.pop() {
+            let seen = &mut visited[pos];
+            let bit = 1 << direction as u8;
+            if *seen & bit != 0 {
+                continue;
+            }
+            *seen |= bit;
+
+            let (first, second) = self.grid[pos].outgoing(direction);
+            for next in std::iter::once(first).chain(second) {
+                if let Some(next_pos) = self.grid.step(pos, next) {
+                    beams.push((next_pos, next));
+                }
+            }
+        }
+
+        visited.iter().filter(|(_, seen)| **seen != 0).count()
+    }
+
+    /// Every tile on the edge, with the direction a beam entering there
+    /// travels in. Corner tiles appear once for each edge they're on.
+    pub fn entry_points(&self) -> Vec<(Position, Direction)> {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let columns = (0..width).flat_map(|x| {
+            [
+                (Point::new(x, 0), Direction::South),
+                (Point::new(x, height - 1), Direction::North),
+            ]
+        });
+        let rows = (0..height).flat_map(|y| {
+            [
+                (Point::new(0, y), Direction::East),
+                (Point::new(width - 1, y), Direction::West),
+            ]
+        });
+        columns.chain(rows).collect()
+    }
+}
+
+impl Solution for Contraption {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input)?;
+        Ok(Self { grid })
+    }
+
+    fn part_one(&self) -> usize {
+        self.energized(Point::new(0, 0), Direction::East)
+    }
+
+    fn part_two(&self) -> usize {
+        self.entry_points()
+            .into_par_iter()
+            .map(|(start, direction)| self.energized(start, direction))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[test]
+    fn test_part_1() {
+        let result = Contraption::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 46);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Contraption::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 51);
+    }
+
+    #[test]
+    fn test_energized() {
+        let contraption = Contraption::parse(INPUT).unwrap();
+        assert_eq!(
+            contraption.energized(Point::new(3, 0), Direction::South),
+            51
+        );
+        // a beam caught between two splitters loops without running forever
+        let looping = Contraption::parse("-.|\n...\n\\./").unwrap();
+        assert_eq!(looping.energized(Point::new(0, 0), Direction::East), 8);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Contraption::parse("").err().unwrap();
+        assert_eq!(err.to_string(), "1:1: expected a row of cells");
+    }
+}
@@ -0,0 +1,6 @@
+use day_16::Contraption;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<Contraption>(16)
+}
@@ -0,0 +1,152 @@
+//! Day 17: Clumsy Crucible
+
+use common::{MaybeAnswer, ParseError, Solution};
+use geometry::{Direction, Point};
+use grid::{Grid, Position};
+use search::dijkstra;
+
+/// How far a crucible must and may go in a straight line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crucible {
+    /// The blocks it must move straight before turning or stopping.
+    pub min_run: u8,
+    /// The blocks it can move straight before it has to turn.
+    pub max_run: u8,
+}
+
+impl Crucible {
+    pub const STANDARD: Crucible = Crucible {
+        min_run: 1,
+        max_run: 3,
+    };
+    pub const ULTRA: Crucible = Crucible {
+        min_run: 4,
+        max_run: 10,
+    };
+}
+
+/// A crucible's place in the search: where it is, which way it's heading,
+/// and how many blocks it has moved in that direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct State {
+    pub pos: Position,
+    pub direction: Direction,
+    pub run: u8,
+}
+
+/// The map of heat loss for each city block.
+pub struct City {
+    pub heat_loss: Grid<u8>,
+}
+
+impl City {
+    /// The least heat lost moving `crucible` from the top-left block to the
+    /// bottom-right one.
+    pub fn least_heat_loss(&self, crucible: Crucible) -> Option<u32> {
+        let start = Point::new(0, 0);
+        let end = Point::new(self.heat_loss.width() - 1, self.heat_loss.height() - 1);
+        let starts = [Direction::East, Direction::South].map(|direction| State {
+            pos: start,
+            direction,
+            run: 0,
+        });
+
+        let neighbours = |&state: &State| {
+            let turns = [
+                state.direction,
+                state.direction.turn_left(),
+                state.direction.turn_right(),
+            ];
+            turns.into_iter().filter_map(move |direction| {
+                let run = if direction == state.direction {
+                    (state.run < crucible.max_run).then_some(state.run + 1)?
+                } else {
+                    (state.run >= crucible.min_run).then_some(1)?
+                };
+                let pos = self.heat_loss.step(state.pos, direction)?;
+                let next = State {
+                    pos,
+                    direction,
+                    run,
+                };
+                Some((next, u32::from(self.heat_loss[pos])))
+            })
+        };
+        let is_goal = |state: &State| state.pos == end && state.run >= crucible.min_run;
+
+        dijkstra(starts, neighbours, is_goal).map(|(_, cost)| cost)
+    }
+}
+
+impl Solution for City {
+    type PartOne = MaybeAnswer<u32>;
+    type PartTwo = MaybeAnswer<u32>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let heat_loss = Grid::parse_with(input, |c| c.to_digit(10).map(|d| d as u8))?;
+        Ok(Self { heat_loss })
+    }
+
+    fn part_one(&self) -> MaybeAnswer<u32> {
+        self.least_heat_loss(Crucible::STANDARD).into()
+    }
+
+    fn part_two(&self) -> MaybeAnswer<u32> {
+        self.least_heat_loss(Crucible::ULTRA).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    #[test]
+    fn test_part_1() {
+        let result = City::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, MaybeAnswer(Some(102)));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = City::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, MaybeAnswer(Some(94)));
+    }
+
+    #[test]
+    fn test_ultra_must_run_to_the_end() {
+        let city = City::parse(
+            "111111111111
+999999999991
+999999999991
+999999999991
+999999999991",
+        )
+        .unwrap();
+        assert_eq!(city.least_heat_loss(Crucible::ULTRA), Some(71));
+
+        // too short for an ultra crucible to stop at the end
+        let city = City::parse("123").unwrap();
+        assert_eq!(city.part_one(), MaybeAnswer(Some(5)));
+        assert_eq!(city.part_two().to_string(), "no answer");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = City::parse("").err().unwrap();
+        assert_eq!(err.to_string(), "1:1: expected a row of cells");
+    }
+}
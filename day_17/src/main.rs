@@ -0,0 +1,6 @@
+use day_17::City;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<City>(17)
+}
@@ -0,0 +1,203 @@
+//! Day 18: Lavaduct Lagoon
+
+use common::{parse_lines, parse_number, ParseError, Solution};
+use geometry::{Area, Direction, Interior};
+
+/// A corner of the trench, `y` increasing downwards.
+pub type Point = geometry::Point<i64>;
+
+/// Dig `length` metres in `direction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub length: u32,
+}
+
+/// The two readings of one line of the plan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// The direction and length as written.
+    pub plain: Instruction,
+    /// The instruction hidden in the colour code.
+    pub hex: Instruction,
+}
+
+impl Step {
+    /// Parses a line like `R 6 (#70c710)`.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let mut tokens = line.split_whitespace();
+        let mut next = |after: &str, expected: &str| {
+            tokens
+                .next()
+                .ok_or_else(|| ParseError::missing(line, after, expected))
+        };
+
+        let direction_str = next(&line[..0], "a direction")?;
+        let length_str = next(direction_str, "a length")?;
+        let colour = next(length_str, "a colour")?;
+
+        let direction = match direction_str {
+            "U" => Direction::North,
+            "R" => Direction::East,
+            "D" => Direction::South,
+            "L" => Direction::West,
+            _ => return Err(ParseError::unexpected(line, direction_str, "U, R, D or L")),
+        };
+        let length = parse_number(line, length_str)?;
+        if length == 0 {
+            return Err(ParseError::unexpected(line, length_str, "a length above 0"));
+        }
+        let plain = Instruction { direction, length };
+
+        let hex = colour
+            .strip_prefix("(#")
+            .and_then(|code| code.strip_suffix(')'))
+            .filter(|code| code.len() == 6 && code.chars().all(|c| c.is_ascii_hexdigit()))
+            .and_then(|code| {
+                let length = u32::from_str_radix(&code[..5], 16)
+                    .ok()
+                    .filter(|&l| l > 0)?;
+                let direction = match &code[5..] {
+                    "0" => Direction::East,
+                    "1" => Direction::South,
+                    "2" => Direction::West,
+                    "3" => Direction::North,
+                    _ => return None,
+                };
+                Some(Instruction { direction, length })
+            })
+            .ok_or_else(|| ParseError::unexpected(line, colour, "a colour like `(#70c710)`"))?;
+
+        Ok(Self { plain, hex })
+    }
+}
+
+/// Whether digging by `instructions` ends back where it started.
+fn ends_at_start(instructions: impl IntoIterator<Item = Instruction>) -> bool {
+    let end = instructions
+        .into_iter()
+        .fold(Point::new(0, 0), |corner, instruction| {
+            corner + instruction.direction.delta() * i64::from(instruction.length)
+        });
+    end == Point::new(0, 0)
+}
+
+/// The cubic metres of lava the lagoon dug by `instructions` holds: the
+/// trench itself plus everything it encloses.
+pub fn lagoon_volume(instructions: impl IntoIterator<Item = Instruction>) -> usize {
+    let mut corner = Point::new(0, 0);
+    let mut corners = vec![corner];
+    let mut boundary = 0;
+    for instruction in instructions {
+        corner += instruction.direction.delta() * i64::from(instruction.length);
+        corners.push(corner);
+        boundary += instruction.length as usize;
+    }
+    if boundary == 0 {
+        return 0;
+    }
+    corners.shoestring().picks(boundary) + boundary
+}
+
+/// The dig plan, in order.
+pub struct DigPlan {
+    pub steps: Vec<Step>,
+}
+
+impl Solution for DigPlan {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let steps = parse_lines(input, Step::parse)?;
+
+        // both readings of the plan must dig a trench back to where it began
+        if !ends_at_start(steps.iter().map(|step| step.plain))
+            || !ends_at_start(steps.iter().map(|step| step.hex))
+        {
+            return Err(ParseError::missing(
+                input,
+                input.trim_end(),
+                "a plan that ends where it starts",
+            ));
+        }
+        Ok(Self { steps })
+    }
+
+    fn part_one(&self) -> usize {
+        lagoon_volume(self.steps.iter().map(|step| step.plain))
+    }
+
+    fn part_two(&self) -> usize {
+        lagoon_volume(self.steps.iter().map(|step| step.hex))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    #[test]
+    fn test_part_1() {
+        let result = DigPlan::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 62);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = DigPlan::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 952408144115);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = DigPlan::parse("R 6 (#70c710)\nD 5 (#0dc57)").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "2:5: expected a colour like `(#70c710)`, found `(#0dc57)`"
+        );
+
+        let err = DigPlan::parse("R 6 (#ééé)").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "1:5: expected a colour like `(#70c710)`, found `(#ééé)`"
+        );
+        let err = DigPlan::parse("R 6 (#+0ff10)").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "1:5: expected a colour like `(#70c710)`, found `(#+0ff10)`"
+        );
+
+        let err = DigPlan::parse("R -6 (#70c710)").err().unwrap();
+        assert_eq!(err.to_string(), "1:3: expected a number, found `-6`");
+
+        let err = DigPlan::parse("R 6 (#000000)\nL 6 (#000002)")
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "1:5: expected a colour like `(#70c710)`, found `(#000000)`"
+        );
+
+        let err = DigPlan::parse("R 6 (#70c710)").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "1:14: expected a plan that ends where it starts"
+        );
+    }
+}
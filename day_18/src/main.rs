@@ -0,0 +1,6 @@
+use day_18::DigPlan;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<DigPlan>(18)
+}
@@ -0,0 +1,417 @@
+//! Day 19: Aplenty
+
+use common::{parse_number, ParseError, Solution};
+use interval::Interval;
+use std::collections::{HashMap, HashSet};
+
+/// The lowest and highest rating a category can have.
+pub const RATINGS: (u64, u64) = (1, 4000);
+
+/// The workflow every part starts in.
+pub const START: &str = "in";
+
+/// One of the four ratings a part has.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Category {
+    ExtremelyCoolLooking,
+    Musical,
+    Aerodynamic,
+    Shiny,
+}
+
+impl Category {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "x" => Some(Category::ExtremelyCoolLooking),
+            "m" => Some(Category::Musical),
+            "a" => Some(Category::Aerodynamic),
+            "s" => Some(Category::Shiny),
+            _ => None,
+        }
+    }
+
+    /// The category's place in a part's ratings.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// A machine part's rating in each category, in `x`, `m`, `a`, `s` order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Part {
+    pub ratings: [u64; 4],
+}
+
+impl Part {
+    /// Parses a line like `{x=787,m=2655,a=1222,s=2876}`, a slice of
+    /// `source`.
+    pub fn parse(source: &str, line: &str) -> Result<Self, ParseError> {
+        let fields = line
+            .strip_prefix('{')
+            .and_then(|rest| rest.strip_suffix('}'))
+            .ok_or_else(|| {
+                ParseError::unexpected(source, line, "a part like `{x=1,m=2,a=3,s=4}`")
+            })?;
+
+        let mut ratings = [None; 4];
+        for field in fields.split(',') {
+            let (name, rating) = field
+                .split_once('=')
+                .ok_or_else(|| ParseError::missing(source, field, "`=`"))?;
+            let category = Category::from_name(name)
+                .ok_or_else(|| ParseError::unexpected(source, name, "x, m, a or s"))?;
+            ratings[category.index()] = Some(parse_number(source, rating)?);
+        }
+
+        match ratings {
+            [Some(x), Some(m), Some(a), Some(s)] => Ok(Self {
+                ratings: [x, m, a, s],
+            }),
+            _ => Err(ParseError::unexpected(
+                source,
+                line,
+                "a rating for x, m, a and s",
+            )),
+        }
+    }
+
+    pub fn total_rating(&self) -> u64 {
+        self.ratings.iter().sum()
+    }
+}
+
+/// Where a rule sends the parts that match it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+impl Target {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            _ => Target::Workflow(name.to_string()),
+        }
+    }
+}
+
+/// A comparison of one rating against a value, like `a<2006`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Condition {
+    pub category: Category,
+    /// Whether the rating must be greater than the value, rather than less.
+    pub greater: bool,
+    pub value: u64,
+}
+
+impl Condition {
+    pub fn matches(&self, part: &Part) -> bool {
+        let rating = part.ratings[self.category.index()];
+        if self.greater {
+            rating > self.value
+        } else {
+            rating < self.value
+        }
+    }
+
+    /// Splits a range of ratings into the ones that match and the ones that
+    /// don't.
+    pub fn split(&self, ratings: Interval<u64>) -> (Option<Interval<u64>>, Option<Interval<u64>>) {
+        if self.greater {
+            let (rest, matching) = ratings.split_at(self.value.saturating_add(1));
+            (matching, rest)
+        } else {
+            ratings.split_at(self.value)
+        }
+    }
+}
+
+/// One rule of a workflow: where to send the parts it matches, every part
+/// when it has no condition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub condition: Option<Condition>,
+    pub target: Target,
+}
+
+impl Rule {
+    /// Parses a rule like `a<2006:qkq` or `rfg`, a slice of `source`.
+    pub fn parse(source: &str, token: &str) -> Result<Self, ParseError> {
+        let Some((comparison, target)) = token.split_once(':') else {
+            return Ok(Self {
+                condition: None,
+                target: Target::from_name(token),
+            });
+        };
+
+        let (name, value, greater) = match comparison.split_once('<') {
+            Some((name, value)) => (name, value, false),
+            None => match comparison.split_once('>') {
+                Some((name, value)) => (name, value, true),
+                None => return Err(ParseError::unexpected(source, comparison, "`<` or `>`")),
+            },
+        };
+        let category = Category::from_name(name)
+            .ok_or_else(|| ParseError::unexpected(source, name, "x, m, a or s"))?;
+        let condition = Condition {
+            category,
+            greater,
+            value: parse_number(source, value)?,
+        };
+
+        Ok(Self {
+            condition: Some(condition),
+            target: Target::from_name(target),
+        })
+    }
+}
+
+/// A range of ratings in each category.
+pub type PartRange = [Interval<u64>; 4];
+
+/// A target, a slice of the input, that leads back to a workflow already
+/// on the way to it, following every workflow reachable from `name`.
+fn find_cycle<'a>(
+    name: &'a str,
+    edges: &HashMap<&'a str, Vec<&'a str>>,
+    on_path: &mut HashSet<&'a str>,
+    done: &mut HashSet<&'a str>,
+) -> Option<&'a str> {
+    if done.contains(name) {
+        return None;
+    }
+    on_path.insert(name);
+    for &target in edges.get(name).into_iter().flatten() {
+        if on_path.contains(target) {
+            return Some(target);
+        }
+        if let Some(target) = find_cycle(target, edges, on_path, done) {
+            return Some(target);
+        }
+    }
+    on_path.remove(name);
+    done.insert(name);
+    None
+}
+
+/// The workflows, and the parts waiting to go through them.
+pub struct System {
+    pub workflows: HashMap<String, Vec<Rule>>,
+    pub parts: Vec<Part>,
+}
+
+impl System {
+    /// Whether `part` ends up accepted after going through the workflows.
+    pub fn accepts(&self, part: &Part) -> bool {
+        let mut name = START;
+        loop {
+            let rule = self.workflows[name]
+                .iter()
+                .find(|rule| rule.condition.is_none_or(|c| c.matches(part)))
+                .expect("parse checks every workflow ends with a rule for every part");
+            match &rule.target {
+                Target::Accept => return true,
+                Target::Reject => return false,
+                Target::Workflow(next) => name = next,
+            }
+        }
+    }
+
+    /// How many of the combinations of ratings in `range` the workflow
+    /// `name` ends up accepting.
+    pub fn accepted_combinations(&self, name: &str, mut range: PartRange) -> u64 {
+        let mut accepted = 0;
+        for rule in &self.workflows[name] {
+            // the ratings the rule sends on, and the ones left for the next
+            let (matching, rest) = match rule.condition {
+                None => (Some(range), None),
+                Some(condition) => {
+                    let index = condition.category.index();
+                    let with = |ratings| {
+                        let mut split = range;
+                        split[index] = ratings;
+                        split
+                    };
+                    let (matching, rest) = condition.split(range[index]);
+                    (matching.map(with), rest.map(with))
+                }
+            };
+
+            if let Some(matching) = matching {
+                accepted += match &rule.target {
+                    Target::Accept => matching.iter().map(Interval::len).product(),
+                    Target::Reject => 0,
+                    Target::Workflow(next) => self.accepted_combinations(next, matching),
+                };
+            }
+            match rest {
+                Some(rest) => range = rest,
+                None => break,
+            }
+        }
+        accepted
+    }
+}
+
+impl Solution for System {
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (workflows_str, parts_str) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::missing(input, input, "a blank line before the parts"))?;
+
+        let mut workflows = HashMap::new();
+        // the workflows each workflow can send parts on to, as they're named
+        // in the input
+        let mut edges: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut names = Vec::new();
+        for line in workflows_str.lines() {
+            let (name, rules_str) = line
+                .strip_suffix('}')
+                .and_then(|line| line.split_once('{'))
+                .ok_or_else(|| {
+                    ParseError::unexpected(input, line, "a workflow like `px{a<2006:qkq,rfg}`")
+                })?;
+            let rules = rules_str
+                .split(',')
+                .map(|token| {
+                    let target = token.rsplit(':').next().unwrap_or(token);
+                    if !matches!(target, "A" | "R") {
+                        edges.entry(name).or_default().push(target);
+                    }
+                    Rule::parse(input, token)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if rules.last().is_some_and(|rule| rule.condition.is_some()) {
+                let last = rules_str.rsplit(',').next().unwrap_or(rules_str);
+                return Err(ParseError::unexpected(
+                    input,
+                    last,
+                    "a last rule without a condition",
+                ));
+            }
+            names.push(name);
+            workflows.insert(name.to_string(), rules);
+        }
+
+        if !workflows.contains_key(START) {
+            return Err(ParseError::missing(
+                input,
+                &input[..0],
+                "a workflow named `in`",
+            ));
+        }
+        if let Some(&target) = names
+            .iter()
+            .flat_map(|name| edges.get(name).into_iter().flatten())
+            .find(|&&target| !workflows.contains_key(target))
+        {
+            return Err(ParseError::unexpected(
+                input,
+                target,
+                "the name of a workflow",
+            ));
+        }
+        let (mut on_path, mut done) = (HashSet::new(), HashSet::new());
+        if let Some(target) = names
+            .iter()
+            .find_map(|name| find_cycle(name, &edges, &mut on_path, &mut done))
+        {
+            return Err(ParseError::unexpected(
+                input,
+                target,
+                "a workflow that doesn't lead back to itself",
+            ));
+        }
+
+        let parts = parts_str
+            .lines()
+            .map(|line| Part::parse(input, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { workflows, parts })
+    }
+
+    fn part_one(&self) -> u64 {
+        self.parts
+            .iter()
+            .filter(|part| self.accepts(part))
+            .map(Part::total_rating)
+            .sum()
+    }
+
+    fn part_two(&self) -> u64 {
+        let (low, high) = RATINGS;
+        let range = [Interval::new(low, high + 1); 4];
+        self.accepted_combinations(START, range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    #[test]
+    fn test_part_1() {
+        let result = System::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 19114);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = System::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 167409079868000);
+
+        // nothing is greater than the largest value, rather than overflowing
+        let input = "in{x>18446744073709551615:R,A}\n\n{x=1,m=1,a=1,s=1}";
+        let result = System::parse(input).unwrap().part_two();
+        assert_eq!(result, 4000u64.pow(4));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("A,lnx}", "A,lxn}");
+        let err = System::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "5:13: expected the name of a workflow, found `lxn`"
+        );
+
+        let input = INPUT.replace("pv{a>1716:R,A}", "pv{a>1716:R,x<5:A}");
+        let err = System::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "2:13: expected a last rule without a condition, found `x<5:A`"
+        );
+
+        let input = INPUT.replace("crn{x>2662:A,R}", "crn{x>2662:A,in}");
+        let err = System::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "8:11: expected a workflow that doesn't lead back to itself, found `px`"
+        );
+    }
+}
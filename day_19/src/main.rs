@@ -0,0 +1,6 @@
+use day_19::System;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<System>(19)
+}
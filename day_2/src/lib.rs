@@ -0,0 +1,152 @@
+//! Day 2: Cube Conundrum
+
+use common::{parse_lines, parse_number, ParseError, Solution};
+
+/// A single game and the cubes revealed on each of its turns.
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub turns: Vec<Turn>,
+}
+
+impl Game {
+    /// Parses a line like `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let id = line
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::missing(line, &line[..0], "`Game <id>:`"))?;
+        let (id, rest) = id
+            .split_once(':')
+            .ok_or_else(|| ParseError::missing(line, id, "`:`"))?;
+        let id = parse_number(line, id)?;
+        let turns = rest
+            .split(';')
+            .map(|segment| Turn::parse(segment).map_err(|err| err.within(line, segment)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { id, turns })
+    }
+}
+
+/// The number of cubes of each color revealed in one handful.
+#[derive(Debug)]
+pub struct Turn {
+    pub red: u32,
+    pub blue: u32,
+    pub green: u32,
+}
+
+impl Turn {
+    /// Parses a comma separated list of counts like `3 blue, 4 red`.
+    pub fn parse(segment: &str) -> Result<Self, ParseError> {
+        let mut red = 0;
+        let mut blue = 0;
+        let mut green = 0;
+        for cubes in segment.split(',').map(str::trim) {
+            let (count, color) = cubes
+                .split_once(' ')
+                .ok_or_else(|| ParseError::missing(segment, cubes, "a color"))?;
+            let count = parse_number(segment, count)?;
+            match color {
+                "red" => red = count,
+                "blue" => blue = count,
+                "green" => green = count,
+                _ => {
+                    return Err(ParseError::unexpected(
+                        segment,
+                        color,
+                        "`red`, `green` or `blue`",
+                    ))
+                }
+            }
+        }
+        Ok(Self { red, blue, green })
+    }
+}
+
+const RED: u32 = 12;
+const GREEN: u32 = 13;
+const BLUE: u32 = 14;
+
+/// Every game recorded in the puzzle input.
+pub struct GameRecord {
+    pub games: Vec<Game>,
+}
+
+impl Solution for GameRecord {
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let games = parse_lines(input, Game::parse)?;
+        Ok(Self { games })
+    }
+
+    fn part_one(&self) -> u32 {
+        let valid_games = self
+            .games
+            .iter()
+            .filter(|game| {
+                game.turns
+                    .iter()
+                    .all(|turn| turn.blue <= BLUE && turn.green <= GREEN && turn.red <= RED)
+            })
+            .collect::<Vec<_>>();
+
+        valid_games.iter().map(|game| game.id).sum()
+    }
+
+    fn part_two(&self) -> u32 {
+        let fewest_die = self
+            .games
+            .iter()
+            .map(|game| {
+                let mut result = Turn {
+                    red: 0,
+                    blue: 0,
+                    green: 0,
+                };
+                for turn in &game.turns {
+                    result.red = result.red.max(turn.red);
+                    result.blue = result.blue.max(turn.blue);
+                    result.green = result.green.max(turn.green);
+                }
+                result.red * result.green * result.blue
+            })
+            .collect::<Vec<_>>();
+
+        fewest_die.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn test_part_1() {
+        let result = GameRecord::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = GameRecord::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 2286);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("4 blue", "4 purple");
+        let err = GameRecord::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "2:37: expected `red`, `green` or `blue`, found `purple`"
+        );
+    }
+}
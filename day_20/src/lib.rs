@@ -0,0 +1,324 @@
+//! Day 20: Pulse Propagation
+
+use common::{parse_lines, MaybeAnswer, ParseError, Solution};
+use math::lcm;
+use std::collections::{HashMap, VecDeque};
+
+/// The module the button sends its low pulse to.
+pub const BROADCASTER: &str = "broadcaster";
+
+/// The module whose first low pulse part two waits for.
+pub const RX: &str = "rx";
+
+/// What a module does with the pulses it receives.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// Sends every pulse on unchanged.
+    Broadcaster,
+    /// `%`: ignores high pulses, and flips on a low one, sending high when
+    /// it turns on and low when it turns off.
+    FlipFlop,
+    /// `&`: remembers the last pulse from each input, sending low once they
+    /// were all high and high otherwise.
+    Conjunction,
+    /// Named as an output but never defined, so it only receives.
+    Sink,
+}
+
+/// A module and the modules it sends to, by index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Module {
+    pub name: String,
+    pub kind: Kind,
+    pub outputs: Vec<usize>,
+    pub inputs: Vec<usize>,
+}
+
+/// One pulse on its way from one module to another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pulse {
+    pub from: usize,
+    pub to: usize,
+    pub high: bool,
+}
+
+/// What the modules remember between pulses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct State {
+    /// Whether each flip-flop is on.
+    pub on: Vec<bool>,
+    /// The last pulse each conjunction got from each of its inputs, in the
+    /// order of the module's `inputs`.
+    pub memory: Vec<Vec<bool>>,
+}
+
+/// The modules and how they're wired together.
+pub struct Network {
+    pub modules: Vec<Module>,
+    pub broadcaster: usize,
+}
+
+impl Network {
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.modules.iter().position(|module| module.name == name)
+    }
+
+    /// Every flip-flop off and every conjunction remembering low pulses.
+    pub fn initial_state(&self) -> State {
+        State {
+            on: vec![false; self.modules.len()],
+            memory: self
+                .modules
+                .iter()
+                .map(|module| vec![false; module.inputs.len()])
+                .collect(),
+        }
+    }
+
+    /// Pushes the button once, delivering pulses in the order they're sent
+    /// and passing each one to `on_pulse`, starting with the button's.
+    pub fn press(&self, state: &mut State, mut on_pulse: impl FnMut(&Pulse)) {
+        let mut queue = VecDeque::from([Pulse {
+            from: self.broadcaster,
+            to: self.broadcaster,
+            high: false,
+        }]);
+
+        while let Some(pulse) = queue.pop_front() {
+            on_pulse(&pulse);
+            let module = &self.modules[pulse.to];
+            let sent = match module.kind {
+                Kind::Broadcaster => Some(pulse.high),
+                Kind::FlipFlop if pulse.high => None,
+                Kind::FlipFlop => {
+                    let on = &mut state.on[pulse.to];
+                    *on = !*on;
+                    Some(*on)
+                }
+                Kind::Conjunction => {
+                    let memory = &mut state.memory[pulse.to];
+                    let input = module
+                        .inputs
+                        .iter()
+                        .position(|&input| input == pulse.from)
+                        .expect("pulses only come from inputs");
+                    memory[input] = pulse.high;
+                    Some(!memory.iter().all(|&high| high))
+                }
+                Kind::Sink => None,
+            };
+
+            if let Some(high) = sent {
+                queue.extend(module.outputs.iter().map(|&to| Pulse {
+                    from: pulse.to,
+                    to,
+                    high,
+                }));
+            }
+        }
+    }
+
+    /// The low and high pulses sent over `presses` presses of the button.
+    pub fn count_pulses(&self, presses: usize) -> (u64, u64) {
+        let mut state = self.initial_state();
+        let (mut low, mut high) = (0, 0);
+        for _ in 0..presses {
+            self.press(&mut state, |pulse| match pulse.high {
+                true => high += 1,
+                false => low += 1,
+            });
+        }
+        (low, high)
+    }
+
+    /// The fewest presses before `rx` gets a low pulse.
+    ///
+    /// `rx` is fed by one conjunction, which sends low only once each of its
+    /// inputs has just sent it high. Those inputs each do so on a fixed
+    /// cycle, so this finds the first press each does and takes the least
+    /// common multiple.
+    pub fn presses_until_rx(&self) -> Option<u64> {
+        let rx = self.find(RX)?;
+        let [feeder] = self.modules[rx].inputs[..] else {
+            return None;
+        };
+        if self.modules[feeder].kind != Kind::Conjunction {
+            return None;
+        }
+
+        let inputs = &self.modules[feeder].inputs;
+        let mut cycles = vec![None; inputs.len()];
+        let mut state = self.initial_state();
+        let mut presses = 0;
+        while cycles.iter().any(Option::is_none) {
+            presses += 1;
+            self.press(&mut state, |pulse| {
+                if pulse.to == feeder && pulse.high {
+                    let input = inputs.iter().position(|&i| i == pulse.from);
+                    if let Some(cycle) = input.map(|i| &mut cycles[i]) {
+                        cycle.get_or_insert(presses);
+                    }
+                }
+            });
+            // a network not built from counters like this may never get there
+            if presses > 1 << 20 {
+                return None;
+            }
+        }
+
+        let cycles = cycles.into_iter().flatten().collect::<Vec<_>>();
+        Some(lcm(&cycles))
+    }
+}
+
+impl Solution for Network {
+    type PartOne = u64;
+    type PartTwo = MaybeAnswer<u64>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let definitions = parse_lines(input, |line| {
+            let (name, outputs) = line
+                .split_once(" -> ")
+                .ok_or_else(|| ParseError::missing(line, line, "` -> `"))?;
+            let (kind, name) = if let Some(name) = name.strip_prefix('%') {
+                (Kind::FlipFlop, name)
+            } else if let Some(name) = name.strip_prefix('&') {
+                (Kind::Conjunction, name)
+            } else if name == BROADCASTER {
+                (Kind::Broadcaster, name)
+            } else {
+                let expected = "`%name`, `&name` or `broadcaster`";
+                return Err(ParseError::unexpected(line, name, expected));
+            };
+            let outputs = outputs.split(", ").map(String::from).collect::<Vec<_>>();
+            Ok((name.to_string(), kind, outputs))
+        })?;
+
+        let mut modules = definitions
+            .iter()
+            .map(|(name, kind, _)| Module {
+                name: name.clone(),
+                kind: *kind,
+                outputs: Vec::new(),
+                inputs: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+        let mut indices = modules
+            .iter()
+            .enumerate()
+            .map(|(i, module)| (module.name.clone(), i))
+            .collect::<HashMap<_, _>>();
+
+        for (from, (_, _, outputs)) in definitions.iter().enumerate() {
+            for output in outputs {
+                let to = *indices.entry(output.clone()).or_insert_with(|| {
+                    modules.push(Module {
+                        name: output.clone(),
+                        kind: Kind::Sink,
+                        outputs: Vec::new(),
+                        inputs: Vec::new(),
+                    });
+                    modules.len() - 1
+                });
+                modules[from].outputs.push(to);
+                modules[to].inputs.push(from);
+            }
+        }
+
+        let broadcaster = indices
+            .get(BROADCASTER)
+            .copied()
+            .filter(|&i| modules[i].kind == Kind::Broadcaster)
+            .ok_or_else(|| ParseError::missing(input, &input[..0], "a `broadcaster` module"))?;
+        Ok(Self {
+            modules,
+            broadcaster,
+        })
+    }
+
+    fn part_one(&self) -> u64 {
+        let (low, high) = self.count_pulses(1000);
+        low * high
+    }
+
+    fn part_two(&self) -> MaybeAnswer<u64> {
+        self.presses_until_rx().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    const INPUT_2: &str = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    // Two counters that reset after 3 and 5 presses, each inverted into the
+    // conjunction that feeds rx
+    const COUNTERS: &str = "broadcaster -> a1, b1
+%a1 -> a2, x
+%a2 -> x
+&x -> a1, ix
+&ix -> hub
+%b1 -> b2, y
+%b2 -> b3
+%b3 -> y
+&y -> b2, b1, iy
+&iy -> hub
+&hub -> rx";
+
+    #[test]
+    fn test_part_1() {
+        let result = Network::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 32000000);
+        let result = Network::parse(INPUT_2).unwrap().part_one();
+        assert_eq!(result, 11687500);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Network::parse(COUNTERS).unwrap().part_two();
+        assert_eq!(result, MaybeAnswer(Some(15)));
+        // the first example has no `rx` module to wait for
+        let result = Network::parse(INPUT).unwrap().part_two();
+        assert_eq!(result.to_string(), "no answer");
+    }
+
+    #[test]
+    fn test_press() {
+        let network = Network::parse(INPUT_2).unwrap();
+        let mut state = network.initial_state();
+        let mut pulses = Vec::new();
+        network.press(&mut state, |pulse| {
+            let from = &network.modules[pulse.from].name;
+            let to = &network.modules[pulse.to].name;
+            pulses.push(format!(
+                "{} -{}-> {}",
+                from,
+                if pulse.high { "high" } else { "low" },
+                to
+            ));
+        });
+        assert_eq!(
+            pulses[1..],
+            [
+                "broadcaster -low-> a",
+                "a -high-> inv",
+                "a -high-> con",
+                "inv -low-> b",
+                "con -high-> output",
+                "b -high-> con",
+                "con -low-> output",
+            ]
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use day_20::Network;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<Network>(20)
+}
@@ -0,0 +1,173 @@
+//! Day 21: Step Counter
+
+use common::{ParseError, Solution};
+use grid::Grid;
+use math::nth_in_sequence;
+use std::collections::HashSet;
+
+/// A position on the map, which may be on a repeat of it for part two.
+pub type Point = geometry::Point<i64>;
+
+/// The steps the elf wants to take in part one.
+pub const STEPS: usize = 64;
+
+/// The steps the elf wants to take across the infinite map in part two.
+pub const INFINITE_STEPS: usize = 26501365;
+
+/// The garden: where the rocks are, and where the elf starts.
+pub struct Garden {
+    pub rocks: Grid<bool>,
+    pub start: Point,
+}
+
+impl Garden {
+    /// Whether the garden has a rock at `pos`, the map repeating forever in
+    /// every direction.
+    fn is_rock(&self, pos: Point) -> bool {
+        let (width, height) = (self.rocks.width() as i64, self.rocks.height() as i64);
+        let wrapped = Point::new(pos.x.rem_euclid(width), pos.y.rem_euclid(height));
+        self.rocks[wrapped.try_cast().expect("wrapped into the map")]
+    }
+
+    /// How many plots the elf could end up on after exactly each of
+    /// `checkpoints` steps, in ascending order, walking across repeats of the
+    /// map when `tiled`.
+    pub fn reachable_at(&self, checkpoints: &[usize], tiled: bool) -> Vec<usize> {
+        let last = checkpoints.last().copied().unwrap_or(0);
+        let in_bounds = |pos: Point| {
+            pos.try_cast::<usize>()
+                .is_some_and(|p| self.rocks.contains(p))
+        };
+
+        // a plot first reached after n steps can be reached again after any
+        // later step count of the same parity, by stepping back and forth
+        let mut seen = HashSet::from([self.start]);
+        let mut frontier = vec![self.start];
+        let mut reached = [1, 0];
+        let mut counts = Vec::with_capacity(checkpoints.len());
+        let mut checkpoints = checkpoints.iter().peekable();
+
+        for step in 0..=last {
+            if step > 0 {
+                let mut next = Vec::new();
+                for pos in frontier {
+                    for neighbour in pos.neighbours() {
+                        if (tiled || in_bounds(neighbour))
+                            && !self.is_rock(neighbour)
+                            && seen.insert(neighbour)
+                        {
+                            next.push(neighbour);
+                        }
+                    }
+                }
+                reached[step % 2] += next.len();
+                frontier = next;
+            }
+            while checkpoints
+                .next_if(|&&checkpoint| checkpoint == step)
+                .is_some()
+            {
+                counts.push(reached[step % 2]);
+            }
+        }
+        counts
+    }
+
+    /// How many plots the elf could end up on after exactly `steps` steps.
+    pub fn reachable(&self, steps: usize, tiled: bool) -> usize {
+        self.reachable_at(&[steps], tiled)[0]
+    }
+
+    /// How many plots the elf could end up on after exactly `steps` steps
+    /// across the infinite map, for step counts too big to walk.
+    ///
+    /// On a square map with clear paths out from the start, the count grows
+    /// quadratically with each map width walked, so three samples a map
+    /// width apart are enough to extrapolate the rest.
+    pub fn reachable_extrapolated(&self, steps: usize) -> usize {
+        let size = self.rocks.width();
+        let (widths, rest) = (steps / size, steps % size);
+        if widths < 3 {
+            return self.reachable(steps, true);
+        }
+
+        let checkpoints = [rest, rest + size, rest + 2 * size];
+        let samples = self
+            .reachable_at(&checkpoints, true)
+            .into_iter()
+            .map(|count| count as i64)
+            .collect();
+        nth_in_sequence(samples, widths) as usize
+    }
+}
+
+impl Solution for Garden {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse_with(input, |c| matches!(c, '.' | '#' | 'S').then_some(c))?;
+        let start = map
+            .position(|c| *c == 'S')
+            .ok_or_else(|| ParseError::missing(input, input, "a start tile `S`"))?;
+        let rocks = map.map(|c| *c == '#');
+        let start = start.try_cast().expect("the map fits in i64");
+        Ok(Self { rocks, start })
+    }
+
+    fn part_one(&self) -> usize {
+        self.reachable(STEPS, false)
+    }
+
+    fn part_two(&self) -> usize {
+        self.reachable_extrapolated(INFINITE_STEPS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    // Clear rows and columns through the start, like the real input
+    const OPEN: &str = ".......
+.#...#.
+..#.#..
+...S...
+..#.#..
+.#...#.
+.......";
+
+    #[test]
+    fn test_part_1() {
+        let garden = Garden::parse(INPUT).unwrap();
+        assert_eq!(garden.reachable(6, false), 16);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let garden = Garden::parse(INPUT).unwrap();
+        assert_eq!(
+            garden.reachable_at(&[6, 10, 50, 100], true),
+            [16, 50, 1594, 6536]
+        );
+
+        let garden = Garden::parse(OPEN).unwrap();
+        let steps = 3 + 7 * 9;
+        assert_eq!(
+            garden.reachable_extrapolated(steps),
+            garden.reachable(steps, true)
+        );
+    }
+}
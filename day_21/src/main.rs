@@ -0,0 +1,6 @@
+use day_21::Garden;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<Garden>(21)
+}
@@ -0,0 +1,253 @@
+//! Day 22: Sand Slabs
+
+use common::{parse_lines, parse_number, ParseError, Solution};
+use geometry::Point;
+use grid::Grid;
+use std::collections::VecDeque;
+
+/// A brick of sand: every cube from `start` to `end` inclusive, as `x`, `y`,
+/// `z` coordinates with `z` counting up from the ground at 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Brick {
+    pub start: [usize; 3],
+    pub end: [usize; 3],
+}
+
+impl Brick {
+    /// Parses a line like `1,0,1~1,2,1`.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (start_str, end_str) = line
+            .split_once('~')
+            .ok_or_else(|| ParseError::missing(line, line, "`~`"))?;
+        let corner = |corner: &str| -> Result<[usize; 3], ParseError> {
+            let coordinates = corner
+                .split(',')
+                .map(|token| parse_number(line, token))
+                .collect::<Result<Vec<_>, _>>()?;
+            coordinates
+                .try_into()
+                .map_err(|_| ParseError::unexpected(line, corner, "3 coordinates"))
+        };
+
+        let (start, end) = (corner(start_str)?, corner(end_str)?);
+        // the ground is at 0, so every brick starts above it
+        for (corner, token) in [(start, start_str), (end, end_str)] {
+            if corner[2] < 1 {
+                return Err(ParseError::unexpected(line, token, "a `z` of at least 1"));
+            }
+        }
+        // keep each coordinate of the start no bigger than the end's
+        Ok(Self {
+            start: [0, 1, 2].map(|i| start[i].min(end[i])),
+            end: [0, 1, 2].map(|i| start[i].max(end[i])),
+        })
+    }
+
+    pub fn bottom(&self) -> usize {
+        self.start[2]
+    }
+
+    pub fn top(&self) -> usize {
+        self.end[2]
+    }
+
+    /// The cells the brick covers, seen from above.
+    pub fn footprint(&self) -> impl Iterator<Item = Point<usize>> {
+        let (start, end) = (self.start, self.end);
+        (start[1]..=end[1]).flat_map(move |y| (start[0]..=end[0]).map(move |x| Point::new(x, y)))
+    }
+
+    /// The brick moved down so its bottom is at `z`.
+    pub fn dropped_to(&self, z: usize) -> Self {
+        let fall = self.bottom() - z;
+        let mut brick = *self;
+        brick.start[2] -= fall;
+        brick.end[2] -= fall;
+        brick
+    }
+}
+
+/// Which bricks rest on which, by index into the settled bricks.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SupportGraph {
+    /// The bricks resting directly on each brick.
+    pub supports: Vec<Vec<usize>>,
+    /// The bricks each brick rests directly on.
+    pub supported_by: Vec<Vec<usize>>,
+}
+
+impl SupportGraph {
+    /// Whether the brick can be removed without any other brick falling.
+    pub fn is_safe_to_disintegrate(&self, brick: usize) -> bool {
+        self.supports[brick]
+            .iter()
+            .all(|&above| self.supported_by[above].len() > 1)
+    }
+
+    /// How many other bricks fall if `brick` is removed.
+    pub fn chain_reaction(&self, brick: usize) -> usize {
+        let mut fallen = vec![false; self.supports.len()];
+        fallen[brick] = true;
+        let mut queue = VecDeque::from([brick]);
+        let mut count = 0;
+
+        while let Some(current) = queue.pop_front() {
+            for &above in &self.supports[current] {
+                if !fallen[above] && self.supported_by[above].iter().all(|&b| fallen[b]) {
+                    fallen[above] = true;
+                    count += 1;
+                    queue.push_back(above);
+                }
+            }
+        }
+        count
+    }
+}
+
+/// The bricks once they've all fallen as far as they can.
+pub struct Settled {
+    /// The bricks, ordered by their bottom.
+    pub bricks: Vec<Brick>,
+    pub graph: SupportGraph,
+}
+
+/// The snapshot of falling bricks.
+pub struct Snapshot {
+    pub bricks: Vec<Brick>,
+}
+
+impl Snapshot {
+    /// Drops every brick, lowest first, onto whatever is below it, tracking
+    /// the top of the pile over each cell of the ground the bricks cover.
+    pub fn settle(&self) -> Settled {
+        let mut bricks = self.bricks.clone();
+        bricks.sort_by_key(Brick::bottom);
+
+        let low = |axis: usize| bricks.iter().map(|b| b.start[axis]).min().unwrap_or(0);
+        let high = |axis: usize| bricks.iter().map(|b| b.end[axis] + 1).max().unwrap_or(0);
+        let (left, front) = (low(0), low(1));
+        let (width, depth) = (high(0) - left, high(1) - front);
+        let cell = |pos: Point<usize>| Point::new(pos.x - left, pos.y - front);
+        // the height of the pile over each cell, and the brick on top there
+        let mut heights = Grid::new(width, depth, vec![(0, None); width * depth]);
+        let mut graph = SupportGraph {
+            supports: vec![Vec::new(); bricks.len()],
+            supported_by: vec![Vec::new(); bricks.len()],
+        };
+
+        for (i, brick) in bricks.iter_mut().enumerate() {
+            let rest = brick
+                .footprint()
+                .map(|pos| heights[cell(pos)].0)
+                .max()
+                .unwrap_or(0);
+            *brick = brick.dropped_to(rest + 1);
+
+            for pos in brick.footprint().map(cell) {
+                let (height, below) = heights[pos];
+                if let Some(below) = below.filter(|_| height == rest) {
+                    if !graph.supported_by[i].contains(&below) {
+                        graph.supported_by[i].push(below);
+                        graph.supports[below].push(i);
+                    }
+                }
+                heights[pos] = (brick.top(), Some(i));
+            }
+        }
+
+        Settled { bricks, graph }
+    }
+}
+
+impl Solution for Snapshot {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let bricks = parse_lines(input, Brick::parse)?;
+        Ok(Self { bricks })
+    }
+
+    fn part_one(&self) -> usize {
+        let graph = self.settle().graph;
+        (0..graph.supports.len())
+            .filter(|&brick| graph.is_safe_to_disintegrate(brick))
+            .count()
+    }
+
+    fn part_two(&self) -> usize {
+        let graph = self.settle().graph;
+        (0..graph.supports.len())
+            .map(|brick| graph.chain_reaction(brick))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+    #[test]
+    fn test_part_1() {
+        let result = Snapshot::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Snapshot::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 7);
+    }
+
+    #[test]
+    fn test_support_graph() {
+        let settled = Snapshot::parse(INPUT).unwrap().settle();
+        let bottoms = settled.bricks.iter().map(Brick::bottom).collect::<Vec<_>>();
+        assert_eq!(bottoms, [1, 2, 2, 3, 3, 4, 5]);
+        assert_eq!(
+            settled.graph.supports,
+            [
+                vec![1, 2],
+                vec![3, 4],
+                vec![3, 4],
+                vec![5],
+                vec![5],
+                vec![6],
+                vec![]
+            ]
+        );
+        assert_eq!(settled.graph.supported_by[3], [1, 2]);
+        assert_eq!(settled.graph.chain_reaction(0), 6);
+    }
+
+    #[test]
+    fn test_far_from_origin() {
+        // the pile is only as big as the ground the bricks cover
+        let mut snapshot = Snapshot::parse(INPUT).unwrap();
+        for brick in &mut snapshot.bricks {
+            for corner in [&mut brick.start, &mut brick.end] {
+                corner[0] += 1 << 40;
+                corner[1] += 1 << 40;
+            }
+        }
+        assert_eq!((snapshot.part_one(), snapshot.part_two()), (5, 7));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("1,1,8~", "1,1,0~");
+        let err = Snapshot::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "7:1: expected a `z` of at least 1, found `1,1,0`"
+        );
+    }
+}
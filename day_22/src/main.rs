@@ -0,0 +1,6 @@
+use day_22::Snapshot;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<Snapshot>(22)
+}
@@ -0,0 +1,283 @@
+//! Day 23: A Long Walk
+
+use common::{MaybeAnswer, ParseError, Solution};
+use geometry::Direction;
+use grid::{Grid, Position};
+
+/// A tile of the hiking map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Path,
+    Forest,
+    /// `^`, `>`, `v` or `<`: can only be walked off downhill.
+    Slope(Direction),
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Tile::Path),
+            '#' => Ok(Tile::Forest),
+            '^' => Ok(Tile::Slope(Direction::North)),
+            '>' => Ok(Tile::Slope(Direction::East)),
+            'v' => Ok(Tile::Slope(Direction::South)),
+            '<' => Ok(Tile::Slope(Direction::West)),
+            _ => Err(c),
+        }
+    }
+}
+
+/// The trails reduced to the junctions where they branch, and the lengths
+/// of the corridors between them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Graph {
+    /// The junctions at the end of each junction's corridors, with the
+    /// corridor's length in steps.
+    pub edges: Vec<Vec<(usize, usize)>>,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Graph {
+    /// The most steps of a hike from the start to the end that never visits
+    /// a junction twice, if there's any hike at all. The junctions visited
+    /// are tracked as bits of a `u128` where they fit, and as a list of
+    /// flags on bigger maps.
+    pub fn longest_path(&self) -> Option<usize> {
+        if self.edges.len() <= u128::BITS as usize {
+            return self.longest_from(self.start, 1 << self.start);
+        }
+        let mut visited = vec![false; self.edges.len()];
+        visited[self.start] = true;
+        self.longest_from_flagged(self.start, &mut visited)
+    }
+
+    /// The length of the corridor from `current` straight to the end, if
+    /// there is one. The end has a single corridor, so leaving the junction
+    /// at the other end of it any other way would cut the end off.
+    fn corridor_to_end(&self, current: usize) -> Option<usize> {
+        self.edges[current]
+            .iter()
+            .find(|(to, _)| *to == self.end)
+            .map(|&(_, length)| length)
+    }
+
+    /// The longest path on from `current`, having visited the junctions in
+    /// `visited`.
+    fn longest_from(&self, current: usize, visited: u128) -> Option<usize> {
+        if current == self.end {
+            return Some(0);
+        }
+        if let Some(length) = self.corridor_to_end(current) {
+            return Some(length);
+        }
+
+        self.edges[current]
+            .iter()
+            .filter(|(to, _)| visited & (1 << to) == 0)
+            .filter_map(|&(to, length)| {
+                let rest = self.longest_from(to, visited | (1 << to))?;
+                Some(length + rest)
+            })
+            .max()
+    }
+
+    /// [`Graph::longest_from`] for maps with too many junctions for a
+    /// `u128`, flagging them in `visited` and clearing them again on the
+    /// way back.
+    fn longest_from_flagged(&self, current: usize, visited: &mut [bool]) -> Option<usize> {
+        if current == self.end {
+            return Some(0);
+        }
+        if let Some(length) = self.corridor_to_end(current) {
+            return Some(length);
+        }
+
+        let mut longest = None;
+        for &(to, length) in &self.edges[current] {
+            if visited[to] {
+                continue;
+            }
+            visited[to] = true;
+            if let Some(rest) = self.longest_from_flagged(to, visited) {
+                longest = longest.max(Some(length + rest));
+            }
+            visited[to] = false;
+        }
+        longest
+    }
+}
+
+/// The map of the hiking trails.
+pub struct Trails {
+    pub map: Grid<Tile>,
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Trails {
+    /// The directions a hiker can leave `pos` in, only downhill off a slope
+    /// when `slippery`.
+    fn exits(&self, pos: Position, slippery: bool) -> impl Iterator<Item = Position> + '_ {
+        let downhill = match self.map[pos] {
+            Tile::Slope(direction) if slippery => Some(direction),
+            _ => None,
+        };
+        Direction::ALL
+            .into_iter()
+            .filter(move |&direction| downhill.is_none_or(|d| d == direction))
+            .filter_map(move |direction| self.map.step(pos, direction))
+            .filter(|&next| self.map[next] != Tile::Forest)
+    }
+
+    /// Whether the trail branches at `pos`, counting the start and end.
+    fn is_junction(&self, pos: Position) -> bool {
+        if pos == self.start || pos == self.end {
+            return true;
+        }
+        self.map[pos] != Tile::Forest && self.exits(pos, false).count() > 2
+    }
+
+    /// Collapses each corridor between junctions into a single edge.
+    pub fn compress(&self, slippery: bool) -> Graph {
+        let junctions = self
+            .map
+            .positions()
+            .filter(|&pos| self.is_junction(pos))
+            .collect::<Vec<_>>();
+        let index = |pos: Position| junctions.iter().position(|&j| j == pos);
+
+        let edges = junctions
+            .iter()
+            .map(|&junction| {
+                self.exits(junction, slippery)
+                    .filter_map(|first| {
+                        // follow the corridor, which never branches, until
+                        // the next junction or a dead end
+                        let (mut previous, mut current) = (junction, first);
+                        let mut length = 1;
+                        while !self.is_junction(current) {
+                            let next = self
+                                .exits(current, slippery)
+                                .find(|&next| next != previous)?;
+                            (previous, current) = (current, next);
+                            length += 1;
+                        }
+                        Some((index(current)?, length))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Graph {
+            edges,
+            start: index(self.start).expect("the start is a junction"),
+            end: index(self.end).expect("the end is a junction"),
+        }
+    }
+}
+
+impl Solution for Trails {
+    type PartOne = MaybeAnswer<usize>;
+    type PartTwo = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::<Tile>::parse(input)?;
+        let opening = |y: usize| {
+            (0..map.width())
+                .map(|x| Position::new(x, y))
+                .find(|&pos| map[pos] == Tile::Path)
+        };
+        let start = opening(0)
+            .ok_or_else(|| ParseError::missing(input, &input[..0], "a path in the top row"))?;
+        let end = opening(map.height() - 1).ok_or_else(|| {
+            ParseError::missing(input, &input[input.len()..], "a path in the bottom row")
+        })?;
+        Ok(Self { map, start, end })
+    }
+
+    fn part_one(&self) -> MaybeAnswer<usize> {
+        self.compress(true).longest_path().into()
+    }
+
+    fn part_two(&self) -> MaybeAnswer<usize> {
+        self.compress(false).longest_path().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    #[test]
+    fn test_part_1() {
+        let result = Trails::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, MaybeAnswer(Some(94)));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Trails::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, MaybeAnswer(Some(154)));
+    }
+
+    #[test]
+    fn test_compress() {
+        let trails = Trails::parse(INPUT).unwrap();
+        let graph = trails.compress(false);
+        assert_eq!(graph.edges.len(), 9);
+        let (to, length) = graph.edges[graph.start][0];
+        assert_eq!(length, 15);
+        assert!(graph.edges[to].contains(&(graph.start, 15)));
+    }
+
+    #[test]
+    fn test_many_junctions() {
+        // a chain of 129 junctions, one more than a u128 can track
+        let chain = |len: usize| {
+            (0..len)
+                .map(|i| {
+                    let ends = [i.checked_sub(1), Some(i + 1).filter(|&to| to < len)];
+                    ends.into_iter().flatten().map(|to| (to, 1)).collect()
+                })
+                .collect::<Vec<Vec<_>>>()
+        };
+        let mut graph = Graph {
+            edges: chain(129),
+            start: 0,
+            end: 128,
+        };
+        assert_eq!(graph.longest_path(), Some(128));
+
+        // with the end cut off from the rest there's no hike at all
+        graph.edges = chain(128);
+        graph.edges.push(Vec::new());
+        assert_eq!(MaybeAnswer(graph.longest_path()).to_string(), "no answer");
+    }
+}
@@ -0,0 +1,6 @@
+use day_23::Trails;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<Trails>(23)
+}
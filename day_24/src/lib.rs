@@ -0,0 +1,229 @@
+//! Day 24: Never Tell Me The Odds
+
+use common::{parse_lines, parse_number, MaybeAnswer, ParseError, Solution};
+use num::rational::Ratio;
+use num::{BigInt, BigRational, Zero};
+
+/// The range of `x` and `y` that part one looks for crossings in.
+pub const TEST_AREA: (i64, i64) = (200000000000000, 400000000000000);
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn sub(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// A hailstone's position at time zero, and how far it moves each
+/// nanosecond.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hailstone {
+    pub position: [i64; 3],
+    pub velocity: [i64; 3],
+}
+
+impl Hailstone {
+    /// Parses a line like `19, 13, 30 @ -2, 1, -2`.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (position, velocity) = line
+            .split_once('@')
+            .ok_or_else(|| ParseError::missing(line, line, "`@`"))?;
+        let vector = |vector: &str| -> Result<[i64; 3], ParseError> {
+            let components = vector
+                .split(',')
+                .map(|token| parse_number(line, token.trim()))
+                .collect::<Result<Vec<_>, _>>()?;
+            components
+                .try_into()
+                .map_err(|_| ParseError::unexpected(line, vector.trim(), "3 components"))
+        };
+
+        Ok(Self {
+            position: vector(position)?,
+            velocity: vector(velocity)?,
+        })
+    }
+
+    fn wide_position(&self) -> [i128; 3] {
+        self.position.map(i128::from)
+    }
+
+    fn wide_velocity(&self) -> [i128; 3] {
+        self.velocity.map(i128::from)
+    }
+
+    /// Where the paths of the two hailstones cross, ignoring `z`, if they
+    /// cross after time zero for both.
+    pub fn crossing_xy(&self, other: &Hailstone) -> Option<[Ratio<i128>; 2]> {
+        let (p, v) = (self.wide_position(), self.wide_velocity());
+        let (q, w) = (other.wide_position(), other.wide_velocity());
+        // solve p + t * v = q + s * w for t and s, by crossing both sides
+        // with w and then v
+        let denominator = v[0] * w[1] - v[1] * w[0];
+        if denominator == 0 {
+            return None;
+        }
+        let d = sub(q, p);
+        let t = Ratio::new(d[0] * w[1] - d[1] * w[0], denominator);
+        let s = Ratio::new(d[0] * v[1] - d[1] * v[0], denominator);
+        if t < Ratio::zero() || s < Ratio::zero() {
+            return None;
+        }
+        Some([0, 1].map(|i| t * v[i] + p[i]))
+    }
+}
+
+/// Solves the square system whose rows are each coefficients followed by
+/// the right-hand side, if it has exactly one solution.
+fn solve(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+    for column in 0..n {
+        let pivot = (column..n).find(|&row| !rows[row][column].is_zero())?;
+        rows.swap(column, pivot);
+        let pivot = rows[column].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != column && !row[column].is_zero() {
+                let factor = &row[column] / &pivot[column];
+                for (cell, p) in row.iter_mut().zip(&pivot).skip(column) {
+                    *cell -= &factor * p;
+                }
+            }
+        }
+    }
+    Some(
+        rows.into_iter()
+            .enumerate()
+            .map(|(i, row)| &row[n] / &row[i])
+            .collect(),
+    )
+}
+
+/// The hailstones.
+pub struct Hailstorm {
+    pub hailstones: Vec<Hailstone>,
+}
+
+impl Hailstorm {
+    /// How many pairs of hailstones cross paths, ignoring `z`, within
+    /// `min..=max` in both `x` and `y`.
+    pub fn crossings_within(&self, min: i64, max: i64) -> usize {
+        let range = Ratio::from(i128::from(min))..=Ratio::from(i128::from(max));
+        self.hailstones
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| self.hailstones[i + 1..].iter().map(move |b| (a, b)))
+            .filter_map(|(a, b)| a.crossing_xy(b))
+            .filter(|point| point.iter().all(|c| range.contains(c)))
+            .count()
+    }
+
+    /// The throw that hits every hailstone, found from the first three
+    /// hailstones that pin it down.
+    ///
+    /// The rock hits hailstone `i` when `(P - p_i) × (V - v_i) = 0`. The only
+    /// term that isn't linear, `P × V`, is the same for every hailstone, so
+    /// subtracting the equations for two hailstones leaves three linear
+    /// ones, and two such pairs give six equations for the six unknowns.
+    pub fn rock_throw(&self) -> Option<Hailstone> {
+        self.hailstones.windows(3).find_map(|stones| {
+            let mut rows = Vec::new();
+            let (p0, v0) = (stones[0].wide_position(), stones[0].wide_velocity());
+            for other in &stones[1..] {
+                let (p, v) = (other.wide_position(), other.wide_velocity());
+                // P × (v - v0) + (p - p0) × V = p × v - p0 × v0
+                let (w, d) = (sub(v, v0), sub(p, p0));
+                let rhs = sub(cross(p, v), cross(p0, v0));
+                let unit = |k: usize| {
+                    let mut unit = [0; 3];
+                    unit[k] = 1;
+                    unit
+                };
+                for (component, &rhs) in rhs.iter().enumerate() {
+                    let row = (0..3)
+                        .map(|k| cross(unit(k), w)[component])
+                        .chain((0..3).map(|k| cross(d, unit(k))[component]))
+                        .chain([rhs])
+                        .map(|value| BigRational::from(BigInt::from(value)))
+                        .collect();
+                    rows.push(row);
+                }
+            }
+
+            let solution = solve(rows)?;
+            let integers = solution
+                .iter()
+                .map(|value| {
+                    value
+                        .is_integer()
+                        .then(|| value.to_integer().try_into().ok())
+                        .flatten()
+                })
+                .collect::<Option<Vec<i64>>>()?;
+            Some(Hailstone {
+                position: [integers[0], integers[1], integers[2]],
+                velocity: [integers[3], integers[4], integers[5]],
+            })
+        })
+    }
+}
+
+impl Solution for Hailstorm {
+    type PartOne = usize;
+    type PartTwo = MaybeAnswer<i64>;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let hailstones = parse_lines(input, Hailstone::parse)?;
+        Ok(Self { hailstones })
+    }
+
+    fn part_one(&self) -> usize {
+        let (min, max) = TEST_AREA;
+        self.crossings_within(min, max)
+    }
+
+    fn part_two(&self) -> MaybeAnswer<i64> {
+        self.rock_throw()
+            .map(|rock| rock.position.iter().sum())
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+    #[test]
+    fn test_part_1() {
+        let hailstorm = Hailstorm::parse(INPUT).unwrap();
+        assert_eq!(hailstorm.crossings_within(7, 27), 2);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Hailstorm::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, MaybeAnswer(Some(47)));
+
+        // two hailstones don't pin the rock down
+        let few = INPUT.lines().take(2).collect::<Vec<_>>().join("\n");
+        let result = Hailstorm::parse(&few).unwrap().part_two();
+        assert_eq!(result.to_string(), "no answer");
+    }
+
+    #[test]
+    fn test_rock_throw() {
+        let rock = Hailstorm::parse(INPUT).unwrap().rock_throw().unwrap();
+        assert_eq!(rock.position, [24, 13, 10]);
+        assert_eq!(rock.velocity, [-3, 1, 2]);
+    }
+}
@@ -0,0 +1,6 @@
+use day_24::Hailstorm;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<Hailstorm>(24)
+}
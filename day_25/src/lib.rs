@@ -0,0 +1,211 @@
+//! Day 25: Snowverload
+
+use common::{parse_lines, MaybeAnswer, ParseError, Solution};
+use std::collections::{HashMap, VecDeque};
+
+/// The components and the wires between them.
+pub struct Wiring {
+    pub names: Vec<String>,
+    /// The components wired to each component, by index.
+    pub wires: Vec<Vec<usize>>,
+}
+
+impl Wiring {
+    /// The side of a cut between `source` and `sink` with the fewest wires,
+    /// as whether each component is on the source's side, if it cuts fewer
+    /// than `limit` wires. `reverse` has the index of each wire in the list
+    /// of the component at its other end.
+    ///
+    /// Every wire carries one unit of flow either way, so the cut is found
+    /// by augmenting paths until the sink can't be reached.
+    fn cut_between(
+        &self,
+        reverse: &[Vec<usize>],
+        source: usize,
+        sink: usize,
+        limit: usize,
+    ) -> Option<Vec<bool>> {
+        let mut flow = self
+            .wires
+            .iter()
+            .map(|wires| vec![0i8; wires.len()])
+            .collect::<Vec<_>>();
+
+        for _ in 0..limit {
+            // the wire each component was first reached through
+            let mut parent = vec![None; self.wires.len()];
+            let mut seen = vec![false; self.wires.len()];
+            seen[source] = true;
+            let mut queue = VecDeque::from([source]);
+            while let Some(current) = queue.pop_front() {
+                for (i, &next) in self.wires[current].iter().enumerate() {
+                    if !seen[next] && flow[current][i] < 1 {
+                        seen[next] = true;
+                        parent[next] = Some((current, i));
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            if !seen[sink] {
+                return Some(seen);
+            }
+            let mut current = sink;
+            while let Some((from, i)) = parent[current] {
+                flow[from][i] += 1;
+                flow[current][reverse[from][i]] -= 1;
+                current = from;
+            }
+        }
+        None
+    }
+
+    /// The side of a cut splitting the components in two with the fewest
+    /// wires, as whether each component is on the first one's side.
+    ///
+    /// Some component is on the other side from the first, so the smallest
+    /// cut between the first and any other is the smallest overall.
+    pub fn min_cut(&self) -> Option<Vec<bool>> {
+        let reverse = self
+            .wires
+            .iter()
+            .enumerate()
+            .map(|(from, wires)| {
+                wires
+                    .iter()
+                    .map(|&to| self.wires[to].iter().position(|&w| w == from).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut best: Option<(usize, Vec<bool>)> = None;
+        for sink in 1..self.wires.len() {
+            let limit = best.as_ref().map_or(usize::MAX, |(wires, _)| *wires);
+            if let Some(side) = self.cut_between(&reverse, 0, sink, limit) {
+                best = Some((self.cut_wires(&side).len(), side));
+            }
+        }
+        best.map(|(_, side)| side)
+    }
+
+    /// The wires crossing from one side of a cut to the other.
+    pub fn cut_wires(&self, side: &[bool]) -> Vec<(usize, usize)> {
+        self.wires
+            .iter()
+            .enumerate()
+            .filter(|&(from, _)| side[from])
+            .flat_map(|(from, wires)| wires.iter().map(move |&to| (from, to)))
+            .filter(|&(_, to)| !side[to])
+            .collect()
+    }
+}
+
+impl Solution for Wiring {
+    type PartOne = MaybeAnswer<usize>;
+    /// There's no second puzzle on the last day.
+    type PartTwo = &'static str;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let lines = parse_lines(input, |line| {
+            let (name, others) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::missing(line, line, "`: `"))?;
+            let others = others.split(' ').map(String::from).collect::<Vec<_>>();
+            Ok((name.to_string(), others))
+        })?;
+
+        let mut names = Vec::new();
+        let mut wires: Vec<Vec<usize>> = Vec::new();
+        let mut indices = HashMap::new();
+        let mut index = |name: &String| {
+            *indices.entry(name.clone()).or_insert_with(|| {
+                names.push(name.clone());
+                wires.push(Vec::new());
+                names.len() - 1
+            })
+        };
+        let mut pairs = Vec::new();
+        for (name, others) in &lines {
+            let from = index(name);
+            for other in others {
+                pairs.push((from, index(other)));
+            }
+        }
+        for (from, to) in pairs {
+            // a wire listed from both ends, or from a component to itself,
+            // would leave the wires without a single twin to send flow back on
+            if from == to || wires[from].contains(&to) {
+                continue;
+            }
+            wires[from].push(to);
+            wires[to].push(from);
+        }
+
+        Ok(Self { names, wires })
+    }
+
+    fn part_one(&self) -> MaybeAnswer<usize> {
+        self.min_cut()
+            .map(|side| {
+                let first = side.iter().filter(|&&s| s).count();
+                first * (side.len() - first)
+            })
+            .into()
+    }
+
+    fn part_two(&self) -> &'static str {
+        "Merry Christmas"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    #[test]
+    fn test_part_1() {
+        let result = Wiring::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, MaybeAnswer(Some(54)));
+
+        // a single component can't be split in two
+        let result = Wiring::parse("jqt: jqt").unwrap().part_one();
+        assert_eq!(result.to_string(), "no answer");
+    }
+
+    #[test]
+    fn test_duplicate_wires() {
+        let wiring = Wiring::parse(&format!("{}\nxhk: jqt\nhfx: hfx", INPUT)).unwrap();
+        assert_eq!(wiring.wires, Wiring::parse(INPUT).unwrap().wires);
+        assert_eq!(wiring.part_one(), MaybeAnswer(Some(54)));
+    }
+
+    #[test]
+    fn test_min_cut() {
+        let wiring = Wiring::parse(INPUT).unwrap();
+        let side = wiring.min_cut().unwrap();
+        let mut cut = wiring
+            .cut_wires(&side)
+            .into_iter()
+            .map(|(from, to)| {
+                let mut pair = [&wiring.names[from], &wiring.names[to]];
+                pair.sort();
+                pair.map(String::as_str)
+            })
+            .collect::<Vec<_>>();
+        cut.sort();
+        assert_eq!(cut, [["bvb", "cmg"], ["hfx", "pzl"], ["jqt", "nvd"]]);
+    }
+}
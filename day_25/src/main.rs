@@ -0,0 +1,6 @@
+use day_25::Wiring;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run::main::<Wiring>(25)
+}
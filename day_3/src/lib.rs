@@ -0,0 +1,115 @@
+//! Day 3: Gear Ratios
+
+use common::{ParseError, ParseErrorKind, Solution};
+use geometry::Point;
+use grid::{Grid, Position};
+use std::collections::{HashMap, HashSet};
+
+/// A `*` symbol and the part numbers adjacent to it.
+#[derive(Debug)]
+pub struct Gear {
+    pub numbers: Vec<u32>,
+}
+
+fn is_symbol_not_period(char: char) -> bool {
+    !char.is_alphanumeric() && char != '.'
+}
+
+/// The engine schematic: every part number adjacent to a symbol, and the
+/// gears keyed by their position.
+pub struct Schematic {
+    pub numbers: Vec<u32>,
+    pub gears: HashMap<Position, Gear>,
+}
+
+impl Solution for Schematic {
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::<char>::parse(input)?;
+
+        let mut numbers: Vec<u32> = Vec::new();
+        let mut gears: HashMap<Position, Gear> = HashMap::new();
+
+        for (y, row) in grid.rows().enumerate() {
+            let mut end = 0;
+            while end < row.len() {
+                if !row[end].is_ascii_digit() {
+                    end += 1;
+                    continue;
+                }
+                let start = end;
+                while end < row.len() && row[end].is_ascii_digit() {
+                    end += 1;
+                }
+                let token = row[start..end].iter().collect::<String>();
+                let number = token.parse::<u32>().map_err(|_| {
+                    ParseError::new(y + 1, start + 1, &token, ParseErrorKind::InvalidNumber)
+                })?;
+
+                let adjacent = (start..end)
+                    .flat_map(|x| grid.neighbours8(Point::new(x, y)))
+                    .collect::<HashSet<_>>();
+
+                if adjacent.iter().any(|&pos| is_symbol_not_period(grid[pos])) {
+                    numbers.push(number);
+                }
+                for pos in adjacent {
+                    if grid[pos] == '*' {
+                        gears
+                            .entry(pos)
+                            .or_insert_with(|| Gear {
+                                numbers: Vec::new(),
+                            })
+                            .numbers
+                            .push(number);
+                    }
+                }
+            }
+        }
+
+        Ok(Self { numbers, gears })
+    }
+
+    fn part_one(&self) -> u32 {
+        self.numbers.iter().sum()
+    }
+
+    fn part_two(&self) -> u32 {
+        self.gears
+            .values()
+            .filter(|g| g.numbers.len() == 2)
+            .map(|g| g.numbers.iter().product::<u32>())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Schematic;
+    use common::Solution;
+
+    const INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn test_part_1() {
+        let result = Schematic::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 4361);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Schematic::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 467835);
+    }
+}
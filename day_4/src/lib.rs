@@ -0,0 +1,84 @@
+//! Day 4: Scratchcards
+
+use common::{parse_lines, parse_numbers, ParseError, Solution};
+use std::collections::HashSet;
+
+/// The pile of scratchcards, in card order.
+#[derive(Debug)]
+pub struct Pile {
+    pub cards: Vec<Card>,
+}
+
+/// A scratchcard's winning numbers and the numbers you have.
+#[derive(Debug)]
+pub struct Card {
+    pub winning_numbers: HashSet<usize>,
+    pub possible_numbers: HashSet<usize>,
+}
+
+impl Card {
+    /// Parses a line like `Card 1: 41 48 83 | 83 86 6 31`.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (_id_str, rest_of_line) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::missing(line, line, "`:`"))?;
+
+        let (winning_str, possible_str) = rest_of_line
+            .split_once('|')
+            .ok_or_else(|| ParseError::missing(line, rest_of_line, "`|`"))?;
+
+        let winning_numbers = parse_numbers(line, winning_str)?.into_iter().collect();
+        let possible_numbers = parse_numbers(line, possible_str)?.into_iter().collect();
+
+        Ok(Self {
+            winning_numbers,
+            possible_numbers,
+        })
+    }
+
+    /// How many of your numbers are winning numbers.
+    pub fn num_matches(&self) -> usize {
+        self.possible_numbers
+            .intersection(&self.winning_numbers)
+            .count()
+    }
+}
+
+impl Solution for Pile {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let cards = parse_lines(input, Card::parse)?;
+        Ok(Self { cards })
+    }
+
+    fn part_one(&self) -> usize {
+        self.cards
+            .iter()
+            .map(|card| {
+                if card.num_matches() > 0 {
+                    2usize.pow((card.num_matches() - 1) as u32)
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    fn part_two(&self) -> usize {
+        let mut counts = vec![1usize; self.cards.len()];
+        let len = counts.len().saturating_sub(1);
+
+        for (i, card) in self.cards.iter().enumerate() {
+            // cards are never copied past the end of the table
+            let j = (card.num_matches() + i).min(len);
+
+            for k in i + 1..j + 1 {
+                counts[k] += counts[i];
+            }
+        }
+
+        counts.iter().sum()
+    }
+}
@@ -0,0 +1,300 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use common::{parse_lines, parse_numbers, ParseError, Solution};
+use interval::Interval;
+
+/// The seeds to be planted and the category mappings between them and their
+/// locations.
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub mappings: Vec<Mapping>,
+}
+
+impl Almanac {
+    /// The mapping from seeds, which `parse` checks there is.
+    fn seed_mapping(&self) -> &Mapping {
+        self.mappings
+            .iter()
+            .find(|m| m.from == "seed")
+            .expect("parse checks for a seed mapping")
+    }
+
+    /// Maps every seed through each category in turn, returning its location.
+    pub fn trace_through_for_seeds(&self) -> Vec<usize> {
+        let mut mapping = self.seed_mapping();
+        let mut transformed = self
+            .seeds
+            .iter()
+            .map(|seed| mapping.get_dest(seed))
+            .collect::<Vec<_>>();
+
+        while let Some(next_mapping) = self.mappings.iter().find(|m| m.from == mapping.to) {
+            mapping = next_mapping;
+            transformed = transformed
+                .iter()
+                .map(|step| mapping.get_dest(step))
+                .collect();
+        }
+
+        transformed
+    }
+
+    /// Treats the seeds as `(start, length)` pairs and maps each range through
+    /// every category, returning the start of each resulting location range.
+    pub fn trace_through_seed_pairs(&self) -> Vec<usize> {
+        let mut mapping = self.seed_mapping();
+        let mut transformed = self
+            .get_seed_ranges()
+            .iter()
+            .flat_map(|seed| mapping.get_dest_of_pair(seed))
+            .collect::<Vec<_>>();
+
+        while let Some(next_mapping) = self.mappings.iter().find(|m| m.from == mapping.to) {
+            mapping = next_mapping;
+            transformed = transformed
+                .iter()
+                .flat_map(|step| mapping.get_dest_of_pair(step))
+                .collect();
+        }
+
+        transformed.iter().map(|pair| pair.0).collect::<Vec<_>>()
+    }
+
+    /// The seeds as `(start, length)` pairs, which `parse` checks they come
+    /// in.
+    pub fn get_seed_ranges(&self) -> Vec<(usize, usize)> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect()
+    }
+}
+
+/// A `from-to-to map:` section of the almanac.
+pub struct Mapping {
+    pub from: String,
+    pub to: String,
+    pub rows: Vec<MappingRow>,
+}
+
+impl Mapping {
+    /// Parses a section starting with a header like `seed-to-soil map:`,
+    /// followed by one row per line.
+    pub fn parse(section: &str) -> Result<Self, ParseError> {
+        let (header, lines) = section.split_once('\n').unwrap_or((section, ""));
+
+        let name = header
+            .strip_suffix(" map:")
+            .ok_or_else(|| ParseError::missing(section, header, "` map:`"))?;
+        let (from, to) = name
+            .split_once("-to-")
+            .ok_or_else(|| ParseError::unexpected(section, name, "`<from>-to-<to>`"))?;
+        let from = from.to_string();
+        let to = to.to_string();
+
+        let rows =
+            parse_lines(lines, MappingRow::parse).map_err(|err| err.within(section, lines))?;
+
+        Ok(Self { from, to, rows })
+    }
+
+    /// Maps a single source value to its destination.
+    pub fn get_dest(&self, target: &usize) -> usize {
+        let row = self
+            .rows
+            .iter()
+            .find(|row| target >= &row.source_low && target < &row.source_high);
+
+        match row {
+            Some(row) => row.dest_low + target - row.source_low,
+            None => *target,
+        }
+    }
+
+    /// Maps a `(start, length)` range of source values, splitting it wherever
+    /// it straddles the edge of a row.
+    pub fn get_dest_of_pair(&self, target: &(usize, usize)) -> Vec<(usize, usize)> {
+        let mut ret = Vec::new();
+        let mut unmapped = vec![Interval::from_len(target.0, target.1)];
+
+        for row in &self.rows {
+            let source = Interval::new(row.source_low, row.source_high);
+            let mut rest = Vec::new();
+            for interval in unmapped {
+                let (below, inside, above) = interval.split(&source);
+                if let Some(inside) = inside {
+                    let dest_start = row.dest_low + inside.start - row.source_low;
+                    ret.push((dest_start, inside.len()));
+                }
+                rest.extend(below.into_iter().chain(above));
+            }
+            unmapped = rest;
+        }
+
+        // whatever no row covers keeps its value
+        ret.extend(
+            unmapped
+                .iter()
+                .map(|interval| (interval.start, interval.len())),
+        );
+        ret
+    }
+}
+
+/// One `destination source length` line of a mapping, with the source range
+/// stored as `source_low..source_high`.
+pub struct MappingRow {
+    pub source_low: usize,
+    pub source_high: usize,
+    pub dest_low: usize,
+}
+
+impl MappingRow {
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let [dest_start, src_start, len] = parse_numbers(line, line)?[..] else {
+            return Err(ParseError::unexpected(line, line, "3 numbers"));
+        };
+
+        Ok(Self {
+            source_low: src_start,
+            source_high: src_start + len,
+            dest_low: dest_start,
+        })
+    }
+}
+
+impl Solution for Almanac {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut sections = input.split("\n\n");
+        let seeds_str = sections.next().unwrap_or_default();
+        let seeds_list = seeds_str
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::missing(input, &input[..0], "`seeds:`"))?;
+        let seeds = parse_numbers(input, seeds_list)?;
+        if seeds.is_empty() {
+            return Err(ParseError::missing(input, seeds_str, "seed numbers"));
+        }
+        if seeds.len() % 2 != 0 {
+            return Err(ParseError::unexpected(
+                input,
+                seeds_list.trim(),
+                "seeds in pairs of a start and a length",
+            ));
+        }
+
+        let mappings = sections
+            .map(|section| Mapping::parse(section).map_err(|err| err.within(input, section)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if !mappings.iter().any(|m| m.from == "seed") {
+            return Err(ParseError::missing(
+                input,
+                &input[input.len()..],
+                "a `seed-to-*` map",
+            ));
+        }
+
+        Ok(Self { seeds, mappings })
+    }
+
+    fn part_one(&self) -> usize {
+        let transformed = self.trace_through_for_seeds();
+        transformed.into_iter().min().unwrap_or(0)
+    }
+
+    fn part_two(&self) -> usize {
+        let transformed = self.trace_through_seed_pairs();
+        transformed.into_iter().min().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Almanac;
+    use common::Solution;
+
+    const INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn test_part_1() {
+        let almanac = Almanac::parse(INPUT).unwrap();
+        let transformed_values = almanac.trace_through_for_seeds();
+        assert_eq!(transformed_values.iter().min().unwrap(), &35);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let almanac = Almanac::parse(INPUT).unwrap();
+        let transformed_values = almanac.trace_through_seed_pairs();
+        assert_eq!(transformed_values.iter().min().unwrap(), &46);
+    }
+
+    #[test]
+    fn test_range_edges() {
+        // seeds 5..10 start right where the row's 0..5 ends, so none of them
+        // are mapped
+        let almanac = Almanac::parse("seeds: 5 5\n\nseed-to-soil map:\n100 0 5").unwrap();
+        assert_eq!(almanac.part_two(), 5);
+
+        // seeds 3..8 straddle the row's end: 3 and 4 map to 103 and 104, and
+        // the rest keep their values
+        let almanac = Almanac::parse("seeds: 3 5\n\nseed-to-soil map:\n100 0 5").unwrap();
+        let mut values = almanac.trace_through_seed_pairs();
+        values.sort();
+        assert_eq!(values, [5, 103]);
+        assert_eq!(almanac.part_two(), 5);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("37 52 2", "37 52");
+        let err = Almanac::parse(&input).err().unwrap();
+        assert_eq!(err.to_string(), "9:1: expected 3 numbers, found `37 52`");
+
+        let input = INPUT.replace("79 14 55 13", "79 14 55");
+        let err = Almanac::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "1:8: expected seeds in pairs of a start and a length, found `79 14 55`"
+        );
+
+        let input = INPUT.replace("seed-to-soil", "seeds-to-soil");
+        let err = Almanac::parse(&input).err().unwrap();
+        assert_eq!(err.to_string(), "34:1: expected a `seed-to-*` map");
+    }
+}
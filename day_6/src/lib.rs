@@ -0,0 +1,146 @@
+//! Day 6: Wait For It
+
+use common::{parse_number, ParseError, ParseErrorKind, Solution};
+
+/// A race's duration and the record distance to beat.
+pub struct Race {
+    pub time: usize,
+    pub dist: usize,
+}
+
+impl Race {
+    /// The shortest and longest button hold times that beat the record, if
+    /// any do.
+    pub fn get_range_of_winning_times(&self) -> Option<(usize, usize)> {
+        let min = (0..self.time).find(|time_held| {
+            let remaining_time = self.time - time_held;
+            time_held * remaining_time > self.dist
+        })?;
+        let max = (0..self.time).rfind(|time_held| {
+            let remaining_time = self.time - time_held;
+            time_held * remaining_time > self.dist
+        })?;
+        Some((min, max))
+    }
+
+    /// How many button hold times beat the record.
+    pub fn ways_to_win(&self) -> usize {
+        self.get_range_of_winning_times()
+            .map_or(0, |(low, high)| high + 1 - low)
+    }
+}
+
+/// The number tokens following `label` on `line`, a line of `input`.
+fn read_row<'a>(input: &str, line: &'a str, label: &str) -> Result<Vec<&'a str>, ParseError> {
+    let numbers = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::missing(input, &line[..0], format!("`{}`", label)))?;
+    let tokens = numbers.split_whitespace().collect::<Vec<_>>();
+    if tokens.is_empty() {
+        return Err(ParseError::missing(input, line, "a number"));
+    }
+    for token in &tokens {
+        parse_number::<usize>(input, token)?;
+    }
+    Ok(tokens)
+}
+
+/// The time and distance tokens, checked to be numbers and of equal count.
+fn read_rows(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    let (times_str, distances_str) = input
+        .split_once('\n')
+        .ok_or_else(|| ParseError::missing(input, input, "a `Distance:` line"))?;
+
+    let times = read_row(input, times_str, "Time:")?;
+    let distances = read_row(input, distances_str.trim_end(), "Distance:")?;
+    if times.len() != distances.len() {
+        let expected = format!("{} distances", times.len());
+        return Err(ParseError::unexpected(
+            input,
+            distances_str.trim_end(),
+            expected,
+        ));
+    }
+    Ok((times, distances))
+}
+
+fn read_races_part_1(input: &str) -> Result<Vec<Race>, ParseError> {
+    let (times, distances) = read_rows(input)?;
+    times
+        .iter()
+        .zip(distances)
+        .map(|(time, dist)| {
+            Ok(Race {
+                time: parse_number(input, time)?,
+                dist: parse_number(input, dist)?,
+            })
+        })
+        .collect()
+}
+
+fn read_race_part_2(input: &str) -> Result<Race, ParseError> {
+    let (times, distances) = read_rows(input)?;
+    let kerned = |tokens: Vec<&str>| {
+        let number = tokens.concat();
+        number
+            .parse::<usize>()
+            .map_err(|_| ParseError::at(input, tokens[0], ParseErrorKind::InvalidNumber))
+    };
+
+    Ok(Race {
+        time: kerned(times)?,
+        dist: kerned(distances)?,
+    })
+}
+
+/// The race sheet, read both as separate races and as the single race
+/// you get once the bad kerning is ignored.
+pub struct RaceSheet {
+    pub races: Vec<Race>,
+    pub race: Race,
+}
+
+impl Solution for RaceSheet {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let races = read_races_part_1(input)?;
+        let race = read_race_part_2(input)?;
+        Ok(Self { races, race })
+    }
+
+    fn part_one(&self) -> usize {
+        self.races.iter().map(Race::ways_to_win).product()
+    }
+
+    fn part_two(&self) -> usize {
+        self.race.ways_to_win()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn test_part_1() {
+        let sheet = RaceSheet::parse(INPUT).unwrap();
+        assert_eq!(sheet.part_one(), 288)
+    }
+
+    #[test]
+    fn test_part_2() {
+        let sheet = RaceSheet::parse(INPUT).unwrap();
+        assert_eq!(sheet.part_two(), 71503)
+    }
+
+    #[test]
+    fn test_impossible_race() {
+        let race = Race { time: 4, dist: 4 };
+        assert_eq!(race.ways_to_win(), 0);
+    }
+}
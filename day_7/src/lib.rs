@@ -0,0 +1,203 @@
+//! Day 7: Camel Cards
+
+use common::{parse_lines, parse_number, ParseError, Solution};
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::iter::zip;
+
+/// A hand of five cards and its bid. Cards are stored as their rank, `0`
+/// being the lowest.
+#[derive(Clone, Eq)]
+pub struct Hand {
+    cards: [usize; 5],
+    hand_type: usize,
+    bid: usize,
+}
+
+impl Hand {
+    fn _hand_type(cards: &[usize; 5], jokers: bool) -> usize {
+        let mut map = cards.iter().copied().fold(HashMap::new(), |mut map, val| {
+            map.entry(val).and_modify(|frq| *frq += 1).or_insert(1);
+            map
+        });
+
+        if jokers {
+            // if jokers reassign in hashmap then match
+            if let Some(num_jokers) = map.remove(&9) {
+                match map.iter().max_by_key(|(_, &v)| v) {
+                    Some(k) => *map.get_mut(&k.0.to_owned()).unwrap() += num_jokers,
+                    None => {
+                        map.entry(12).or_insert(5);
+                    } // all jokers, pick aces
+                };
+            }
+        }
+
+        let frequencies: Vec<usize> = map.iter().map(|(_, &freq)| freq).sorted().collect();
+        match frequencies[..] {
+            [5] => 7,
+            [1, 4] => 6,
+            [2, 3] => 5,
+            [1, 1, 3] => 4,
+            [1, 2, 2] => 3,
+            [1, 1, 1, 2] => 2,
+            _ => 1,
+        }
+    }
+
+    fn _get_card_label(x: char) -> Option<usize> {
+        let valid_chars = [
+            '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+        ];
+        valid_chars.iter().position(|&c| c == x)
+    }
+
+    /// Parses a line like `32T3K 765`.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (card_str, bid_str) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::missing(line, line, "a bid"))?;
+        let cards = card_str
+            .char_indices()
+            .map(|(i, c)| {
+                Hand::_get_card_label(c).ok_or_else(|| {
+                    let token = &card_str[i..i + c.len_utf8()];
+                    ParseError::unexpected(line, token, "a card label")
+                })
+            })
+            .collect::<Result<Vec<usize>, _>>()?
+            .try_into()
+            .map_err(|_| ParseError::unexpected(line, card_str, "5 cards"))?;
+        Ok(Hand {
+            cards,
+            hand_type: Hand::_hand_type(&cards, false),
+            bid: parse_number(line, bid_str)?,
+        })
+    }
+
+    pub fn cards(&self) -> [usize; 5] {
+        self.cards
+    }
+
+    /// The strength of the hand, from `1` (high card) to `7` (five of a kind).
+    pub fn hand_type(&self) -> usize {
+        self.hand_type
+    }
+
+    pub fn bid(&self) -> usize {
+        self.bid
+    }
+
+    /// Rescores the hand treating `J` as a joker, which also makes it the
+    /// weakest card.
+    pub fn joker_rescore(&mut self) {
+        self.hand_type = Hand::_hand_type(&self.cards, true);
+        for c in &mut self.cards {
+            *c = if *c != 9 { *c + 1 } else { 0 };
+        }
+    }
+}
+
+impl PartialEq<Self> for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        if self.hand_type == other.hand_type {
+            // we could eliminate this check
+            for (a, b) in zip(self.cards, other.cards) {
+                if a != b {
+                    return false;
+                }
+            }
+            return true;
+        }
+        false
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.hand_type.cmp(&other.hand_type) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal => {
+                for (a, b) in zip(self.cards, other.cards) {
+                    if a != b {
+                        return a.cmp(&b);
+                    }
+                }
+                Ordering::Equal
+            }
+        }
+    }
+}
+
+fn total_winnings<'a>(hands: impl Iterator<Item = &'a Hand>) -> usize {
+    hands
+        .sorted()
+        .enumerate()
+        .map(|(idx, h)| h.bid * (idx + 1))
+        .sum()
+}
+
+/// Every hand in the puzzle input, in input order.
+pub struct CamelCards {
+    pub hands: Vec<Hand>,
+}
+
+impl Solution for CamelCards {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let hands = parse_lines(data, Hand::parse)?;
+        Ok(Self { hands })
+    }
+
+    fn part_one(&self) -> usize {
+        total_winnings(self.hands.iter())
+    }
+
+    fn part_two(&self) -> usize {
+        let mut hands = self.hands.clone();
+        for h in &mut hands {
+            h.joker_rescore();
+        }
+        total_winnings(hands.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn example_input() {
+        let data = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        let game = CamelCards::parse(data).unwrap();
+        assert_eq!((game.part_one(), game.part_two()), (6440, 5905));
+    }
+
+    #[test]
+    fn test_hand_type() {
+        assert_eq!(Hand::_hand_type(&[2, 3, 2, 3, 3], false), 5);
+        assert_eq!(Hand::_hand_type(&[13; 5], false), 7)
+    }
+
+    #[test]
+    fn test_hand() {
+        let h = Hand::parse("32T3K 765").unwrap();
+        assert_eq!(h.hand_type, 2);
+        let err = Hand::parse("32X3K 765").err().unwrap();
+        assert_eq!(err.to_string(), "1:3: expected a card label, found `X`");
+    }
+}
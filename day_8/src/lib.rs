@@ -0,0 +1,177 @@
+//! Day 8: Haunted Wasteland
+
+use common::{parse_lines, ParseError, Solution};
+use math::lcm;
+use std::collections::{HashMap, HashSet};
+
+/// The nodes reached by going left or right from a node.
+pub struct Node {
+    pub left: String,
+    pub right: String,
+}
+
+impl Node {
+    /// Parses a line like `AAA = (BBB, CCC)` into the node's key and its
+    /// neighbours.
+    pub fn parse(line: &str) -> Result<(String, Self), ParseError> {
+        let (key, rest) = line
+            .split_once(" = ")
+            .ok_or_else(|| ParseError::missing(line, line, "` = `"))?;
+        let (left, right) = rest
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .and_then(|rest| rest.split_once(", "))
+            .ok_or_else(|| ParseError::unexpected(line, rest, "`(<left>, <right>)`"))?;
+        let node = Node {
+            left: left.to_string(),
+            right: right.to_string(),
+        };
+        Ok((key.to_string(), node))
+    }
+}
+
+/// Which way to go from a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// The left/right instructions and the network of nodes they navigate.
+pub struct Network {
+    pub directions: Vec<Turn>,
+    pub map: HashMap<String, Node>,
+}
+
+impl Network {
+    /// Counts the steps from `starting` until `ZZZ` is reached, or any node
+    /// ending in `Z` when `p1` is false.
+    pub fn get_cycle_length(&self, starting: &str, p1: bool) -> usize {
+        let mut turns = 0usize;
+        let mut current = starting;
+        while (p1 && current != "ZZZ") || (!p1 && !current.ends_with('Z')) {
+            let node = &self.map[current];
+            current = match self.directions[turns % self.directions.len()] {
+                Turn::Left => &node.left,
+                Turn::Right => &node.right,
+            };
+            turns += 1
+        }
+
+        turns
+    }
+}
+
+impl Solution for Network {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (directions, nodes) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::missing(input, input, "a blank line"))?;
+
+        let directions = directions
+            .char_indices()
+            .map(|(i, c)| match c {
+                'L' => Ok(Turn::Left),
+                'R' => Ok(Turn::Right),
+                _ => Err(ParseError::unexpected(
+                    input,
+                    &directions[i..i + c.len_utf8()],
+                    "`L` or `R`",
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if directions.is_empty() {
+            return Err(ParseError::missing(input, &input[..0], "`L` or `R`"));
+        }
+
+        let entries = parse_lines(nodes, Node::parse).map_err(|err| err.within(input, nodes))?;
+        let keys = entries
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect::<HashSet<_>>();
+
+        // every node must lead to nodes that exist
+        for (line, (_, node)) in nodes.lines().zip(&entries) {
+            let (_, targets) = line.split_once(" = ").unwrap_or_default();
+            for (target, at) in [
+                (&node.left, targets.find(node.left.as_str())),
+                (&node.right, targets.rfind(node.right.as_str())),
+            ] {
+                if !keys.contains(target.as_str()) {
+                    let at = at.unwrap_or_default();
+                    let token = &targets[at..at + target.len()];
+                    return Err(ParseError::unexpected(input, token, "a defined node"));
+                }
+            }
+        }
+        if !keys.contains("AAA") {
+            return Err(ParseError::missing(input, nodes.trim_end(), "a node `AAA`"));
+        }
+
+        let map = entries.into_iter().collect();
+        Ok(Self { directions, map })
+    }
+
+    fn part_one(&self) -> usize {
+        self.get_cycle_length("AAA", true)
+    }
+
+    fn part_two(&self) -> usize {
+        let all_starting = self
+            .map
+            .keys()
+            .filter(|key| key.ends_with('A'))
+            .collect::<Vec<_>>();
+
+        let cycles = all_starting
+            .iter()
+            .map(|starting| self.get_cycle_length(starting, false))
+            .collect::<Vec<_>>();
+
+        lcm(&cycles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    #[test]
+    fn test_part_1() {
+        let result = Network::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 6)
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Network::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 6)
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("LLR", "");
+        let err = Network::parse(&input).err().unwrap();
+        assert_eq!(err.to_string(), "1:1: expected `L` or `R`");
+
+        let input = INPUT.replace("(AAA, ZZZ)", "(AAA, ZZY)");
+        let err = Network::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "4:13: expected a defined node, found `ZZY`"
+        );
+
+        let input = INPUT.replace("AAA", "CCC");
+        let err = Network::parse(&input).err().unwrap();
+        assert_eq!(err.to_string(), "5:17: expected a node `AAA`");
+    }
+}
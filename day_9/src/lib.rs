@@ -0,0 +1,78 @@
+//! Day 9: Mirage Maintenance
+
+use common::{parse_lines, parse_numbers, ParseError, Solution};
+pub use math::{build_iterations, next_in_sequence, prev_in_sequence};
+
+/// The OASIS report: the history of every value being tracked.
+pub struct Report {
+    pub histories: Vec<Vec<i32>>,
+}
+
+impl Solution for Report {
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let histories = parse_lines(input, |line| {
+            let history = parse_numbers(line, line)?;
+            match history.len() {
+                0 => Err(ParseError::missing(line, line, "a history of values")),
+                1 => Err(ParseError::unexpected(line, line, "at least 2 values")),
+                _ => Ok(history),
+            }
+        })?;
+        Ok(Self { histories })
+    }
+
+    fn part_one(&self) -> i32 {
+        self.histories
+            .iter()
+            .map(|row| {
+                let iterations = build_iterations(row.clone());
+                next_in_sequence(iterations).expect("parse checks every history has values")
+            })
+            .sum()
+    }
+
+    fn part_two(&self) -> i32 {
+        self.histories
+            .iter()
+            .map(|row| {
+                let iterations = build_iterations(row.clone());
+                prev_in_sequence(iterations).expect("parse checks every history has values")
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn test_part_1() {
+        let result = Report::parse(INPUT).unwrap().part_one();
+        assert_eq!(result, 114);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = Report::parse(INPUT).unwrap().part_two();
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Report::parse("0 3 6\n\n1 3 6").err().unwrap();
+        assert_eq!(err.to_string(), "2:1: expected a history of values");
+        let err = Report::parse("0 3 6\n12").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "2:1: expected at least 2 values, found `12`"
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Points and compass directions on a 2D plane where `y` increases
+//! southwards, matching the row order of the puzzle inputs.
+
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts both coordinates, e.g. from `usize` to `i64`.
+    pub fn cast<U: From<T>>(self) -> Point<U> {
+        Point::new(U::from(self.x), U::from(self.y))
+    }
+
+    /// Converts both coordinates, or `None` if either doesn't fit, e.g. a
+    /// negative `i32` into `usize`.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl<T: Copy + Add<Output = T> + From<i8>> Point<T> {
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+
+    /// The four orthogonal neighbours, clockwise from north.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+}
+
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Point<T> {
+    /// Taxicab distance: the number of orthogonal steps between the points.
+    pub fn manhattan(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Chessboard distance: the number of king moves between the points.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self {
+        Self::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// The change in position from a single step in this direction.
+    pub fn delta<T: From<i8>>(self) -> Point<T> {
+        let (x, y) = match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        };
+        Point::new(T::from(x), T::from(y))
+    }
+}
+
+/// Area of a closed polygon.
+pub trait Area {
+    fn shoestring(&self) -> usize;
+}
+
+/// Shoelace formula over the vertices of a polygon whose last point repeats
+/// the first. The vertices can wind either way.
+impl<T: Copy + Into<i64>> Area for [Point<T>] {
+    fn shoestring(&self) -> usize {
+        let twice_area = self.windows(2).fold(0i64, |acc, matrix| {
+            let (a, b) = (matrix[0], matrix[1]);
+            acc + a.x.into() * b.y.into() - b.x.into() * a.y.into()
+        });
+        (twice_area.unsigned_abs() / 2) as usize
+    }
+}
+
+/// Interior lattice points of a polygon.
+pub trait Interior {
+    fn picks(&self, boundary: usize) -> usize;
+}
+
+/// Pick's theorem: `self` is the polygon's area, `boundary` the number of
+/// lattice points on its edge. A polygon that folds back on itself encloses
+/// nothing, rather than less than nothing.
+impl Interior for usize {
+    fn picks(&self, boundary: usize) -> usize {
+        (self + 1).saturating_sub(boundary / 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 5);
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(a * 3, Point::new(9, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1usize, 6);
+        let b = Point::new(5usize, 11);
+        assert_eq!(a.manhattan(&b), 9);
+        assert_eq!(b.manhattan(&a), 9);
+        assert_eq!(a.chebyshev(&b), 5);
+        assert_eq!(Point::new(-2, 3).manhattan(&Point::new(2, -3)), 10);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let a = Point::new(2usize, 7);
+        assert_eq!(a.try_cast::<i64>(), Some(Point::new(2, 7)));
+        assert_eq!(Point::new(-1i32, 0).try_cast::<usize>(), None);
+        assert_eq!(Point::new(4u8, 1).cast::<i32>(), Point::new(4, 1));
+        assert_eq!(Point::from((1, 2)), Point::new(1, 2));
+        assert_eq!(<(i32, i32)>::from(Point::new(1, 2)), (1, 2));
+    }
+
+    #[test]
+    fn test_area() {
+        let square = [
+            Point::new(0i64, 0),
+            Point::new(0, 2),
+            Point::new(2, 2),
+            Point::new(2, 0),
+            Point::new(0, 0),
+        ];
+        assert_eq!(square.shoestring(), 4);
+        let reversed = square.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(reversed.shoestring(), 4);
+        assert_eq!(square.shoestring().picks(8), 1);
+    }
+
+    #[test]
+    fn test_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(
+                direction.delta::<i32>() + direction.opposite().delta(),
+                Point::new(0, 0)
+            );
+        }
+        assert_eq!(Point::new(0, 0).step(Direction::North), Point::new(0, -1));
+        assert_eq!(
+            Point::new(0, 0).neighbours().collect::<Vec<_>>(),
+            vec![
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0)
+            ]
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! A rectangular grid of cells, as parsed from the character maps most
+//! puzzles use for their input.
+
+use common::{ParseError, ParseErrorKind};
+use geometry::{Direction, Point};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A position in a grid, `y` increasing downwards.
+pub type Position = Point<usize>;
+
+/// Offsets to all eight neighbours, clockwise from north-west.
+const ADJACENT: [Point<isize>; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "cell count must be width * height"
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, converting each character with `T::try_from`.
+    pub fn parse(input: &str) -> Result<Self, ParseError>
+    where
+        T: TryFrom<char>,
+    {
+        Self::parse_with(input, |c| T::try_from(c).ok())
+    }
+
+    /// Parses one row per line, converting each character with `parse_cell`.
+    /// There must be at least one row, and every row must be as wide as the
+    /// first and have at least one cell.
+    pub fn parse_with<F>(input: &str, mut parse_cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for (y, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                let cell = parse_cell(c).ok_or_else(|| {
+                    let expected = "a valid cell".to_string();
+                    ParseError::new(y + 1, x + 1, c, ParseErrorKind::Unexpected { expected })
+                })?;
+                cells.push(cell);
+                row_width += 1;
+            }
+            if row_width == 0 {
+                return Err(ParseError::missing(line, line, "a row of cells").within(input, line));
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(expected) if expected != row_width => {
+                    let expected = format!("a row {} cells wide", expected);
+                    return Err(ParseError::unexpected(line, line, expected).within(input, line));
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+
+        let width = width.ok_or_else(|| ParseError::missing(input, input, "a row of cells"))?;
+        Ok(Self::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    /// The position `delta` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, pos: Position, delta: Point<isize>) -> Option<Position> {
+        let pos = Point::new(
+            pos.x.checked_add_signed(delta.x)?,
+            pos.y.checked_add_signed(delta.y)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The position one step from `pos` in `direction`, if it is inside the
+    /// grid.
+    pub fn step(&self, pos: Position, direction: Direction) -> Option<Position> {
+        self.offset(pos, direction.delta())
+    }
+
+    /// The in-bounds orthogonal neighbours of `pos`.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        ADJACENT
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell alongside its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell matching `predicate`.
+    pub fn position<P>(&self, mut predicate: P) -> Option<Position>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero chunk size, which an empty grid would give
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Builds a new grid with `f` applied to every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+
+    /// Rotates a quarter turn clockwise, so the first column becomes the
+    /// first row in reverse.
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).rev().cloned())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+
+    /// Rotates a quarter turn counter-clockwise, so the last column becomes
+    /// the first row.
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {:?} out of bounds", pos))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {:?} out of bounds", pos))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "abc
+def";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::<char>::parse(INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.position(|c| *c == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(
+            Grid::<char>::parse("ab\nc"),
+            Err(ParseError::new(
+                2,
+                1,
+                "c",
+                ParseErrorKind::Unexpected {
+                    expected: "a row 2 cells wide".to_string()
+                }
+            ))
+        );
+        assert_eq!(
+            Grid::parse_with(INPUT, |c| ('a'..='d').contains(&c).then_some(c)),
+            Err(ParseError::new(
+                2,
+                2,
+                "e",
+                ParseErrorKind::Unexpected {
+                    expected: "a valid cell".to_string()
+                }
+            ))
+        );
+        let err = Grid::<char>::parse("").err().unwrap();
+        assert_eq!(err.to_string(), "1:1: expected a row of cells");
+        let err = Grid::<char>::parse("ab\n\ncd").err().unwrap();
+        assert_eq!(err.to_string(), "2:1: expected a row of cells");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::<char>::parse(INPUT).unwrap();
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8(Point::new(1, 0)).collect::<Vec<_>>(),
+            vec![
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(1, 1),
+                Point::new(0, 1),
+                Point::new(0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::<char>::parse(INPUT).unwrap();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::<char>::parse(INPUT).unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.transpose().transpose(), grid);
+        assert_eq!(grid.to_string(), INPUT);
+    }
+}
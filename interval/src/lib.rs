@@ -0,0 +1,106 @@
+//! Half-open intervals of integers, split against each other so whole ranges
+//! of values can be pushed through rules that treat their parts differently.
+
+use std::ops::{Add, Sub};
+
+/// The values `start..end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// The interval, or `None` if it holds no values.
+    fn non_empty(self) -> Option<Self> {
+        (!self.is_empty()).then_some(self)
+    }
+
+    /// The values below `at`, and those from `at` on.
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (
+            Self::new(self.start, at).non_empty(),
+            Self::new(at, self.end).non_empty(),
+        )
+    }
+
+    /// The values below `other`, inside it, and above it.
+    pub fn split(&self, other: &Self) -> (Option<Self>, Option<Self>, Option<Self>) {
+        let (below, rest) = self.split_at(other.start);
+        let (inside, above) = match rest {
+            Some(rest) => rest.split_at(other.end),
+            None => (None, None),
+        };
+        (below, inside, above)
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T>> Interval<T> {
+    /// The `len` values from `start`.
+    pub fn from_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Default> Interval<T> {
+    /// How many values the interval holds.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_split_at() {
+        let interval = Interval::new(10, 20);
+        assert_eq!(
+            interval.split_at(15),
+            (Some(Interval::new(10, 15)), Some(Interval::new(15, 20)))
+        );
+        assert_eq!(interval.split_at(5), (None, Some(interval)));
+        assert_eq!(interval.split_at(20), (Some(interval), None));
+    }
+
+    #[test]
+    fn test_split() {
+        let interval = Interval::from_len(10, 10);
+        assert_eq!(
+            interval.split(&Interval::new(12, 15)),
+            (
+                Some(Interval::new(10, 12)),
+                Some(Interval::new(12, 15)),
+                Some(Interval::new(15, 20))
+            )
+        );
+        assert_eq!(
+            interval.split(&Interval::new(0, 12)),
+            (
+                None,
+                Some(Interval::new(10, 12)),
+                Some(Interval::new(12, 20))
+            )
+        );
+        assert_eq!(
+            interval.split(&Interval::new(30, 40)),
+            (Some(interval), None, None)
+        );
+        assert_eq!(interval.len(), 10);
+        assert_eq!(Interval::new(5, 3).len(), 0);
+    }
+}
@@ -0,0 +1,94 @@
+//! Arithmetic on integers and integer sequences, shared by the puzzles that
+//! need it.
+
+use num::{Integer, Signed};
+
+/// The least common multiple of every number in `nums`, or one if there are
+/// none.
+pub fn lcm<T: Integer + Copy>(nums: &[T]) -> T {
+    nums.iter().fold(T::one(), |acc, n| acc.lcm(n))
+}
+
+/// Builds the rows of differences for a sequence, down to a row of zeroes or
+/// a single value, which is taken to repeat forever.
+pub fn build_iterations<T: Signed + Copy>(line: Vec<T>) -> Vec<Vec<T>> {
+    let mut iterations = vec![line.clone()];
+    let mut current = line;
+    while current.len() > 1 && current.iter().any(|n| !n.is_zero()) {
+        let next = current[..current.len() - 1]
+            .iter()
+            .zip(current[1..].iter())
+            .map(|(&a, &b)| b - a)
+            .collect::<Vec<_>>();
+        iterations.push(next.clone());
+        current = next;
+    }
+    iterations
+}
+
+/// Extrapolates the next value from the output of [`build_iterations`], if
+/// the sequence had any values.
+pub fn next_in_sequence<T: Signed + Copy>(iterations: Vec<Vec<T>>) -> Option<T> {
+    iterations
+        .iter()
+        .rev()
+        .try_fold(T::zero(), |next, current| Some(next + *current.last()?))
+}
+
+/// Extrapolates the previous value from the output of [`build_iterations`],
+/// if the sequence had any values.
+pub fn prev_in_sequence<T: Signed + Copy>(iterations: Vec<Vec<T>>) -> Option<T> {
+    iterations
+        .iter()
+        .rev()
+        .try_fold(T::zero(), |prev, current| Some(*current.first()? - prev))
+}
+
+/// Extrapolates the value at index `n` of a sequence given by its first
+/// values, by extending each row of [`build_iterations`] one value at a time.
+/// Differences beyond the ones the values give are taken to be zero.
+pub fn nth_in_sequence<T: Signed + Copy>(sequence: Vec<T>, n: usize) -> T {
+    let known = sequence.len();
+    if n < known {
+        return sequence[n];
+    }
+    let mut lasts = build_iterations(sequence)
+        .iter()
+        .map(|row| row.last().copied().unwrap_or_else(T::zero))
+        .collect::<Vec<_>>();
+    for _ in known..=n {
+        for row in (0..lasts.len() - 1).rev() {
+            lasts[row] = lasts[row] + lasts[row + 1];
+        }
+    }
+    lasts[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(&[4, 6, 10]), 60);
+        assert_eq!(lcm(&[3001u64, 3917, 4027, 4057]), 3001 * 3917 * 4027 * 4057);
+        assert_eq!(lcm::<usize>(&[]), 1);
+    }
+
+    #[test]
+    fn test_sequence() {
+        let squares = vec![0i64, 1, 4, 9];
+        assert_eq!(
+            next_in_sequence(build_iterations(squares.clone())),
+            Some(16)
+        );
+        assert_eq!(prev_in_sequence(build_iterations(squares.clone())), Some(1));
+        assert_eq!(nth_in_sequence(squares.clone(), 2), 4);
+        assert_eq!(nth_in_sequence(squares, 1_000_000), 1_000_000_000_000);
+
+        // too few values to reach zero, so the last difference repeats
+        assert_eq!(next_in_sequence(build_iterations(vec![1, 2, 4])), Some(7));
+        assert_eq!(next_in_sequence(build_iterations(vec![12])), Some(12));
+        assert_eq!(prev_in_sequence(build_iterations(Vec::<i32>::new())), None);
+    }
+}
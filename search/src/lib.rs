@@ -0,0 +1,99 @@
+//! Shortest-path search over graphs described by a function from each state
+//! to its neighbours, so the graph never has to be built up front.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Dijkstra's algorithm from any of `starts` to the cheapest state that
+/// `is_goal` accepts, returning that state and its cost.
+///
+/// `neighbours` gives the states reachable from a state, each with the cost
+/// of the move, which must not be negative. States only need to be hashable,
+/// so they can carry whatever the moves depend on, such as a heading.
+pub fn dijkstra<S, C, N, I, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: N,
+    mut is_goal: G,
+) -> Option<(S, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    // the heap refers to states by index, so they needn't be ordered
+    let mut states = Vec::new();
+    let mut best = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if best.insert(start.clone(), C::default()).is_none() {
+            queue.push(Reverse((C::default(), states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        let state = states[index].clone();
+        if best[&state] < cost {
+            // a cheaper way here was found after this one was queued
+            continue;
+        }
+        if is_goal(&state) {
+            return Some((state, cost));
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if best.get(&next).is_none_or(|&known| next_cost < known) {
+                best.insert(next.clone(), next_cost);
+                queue.push(Reverse((next_cost, states.len())));
+                states.push(next);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_dijkstra() {
+        let edges = [
+            ('a', 'b', 7),
+            ('a', 'c', 9),
+            ('a', 'f', 14),
+            ('b', 'c', 10),
+            ('b', 'd', 15),
+            ('c', 'd', 11),
+            ('c', 'f', 2),
+            ('d', 'e', 6),
+            ('e', 'f', 9),
+        ];
+        let neighbours = |node: &char| {
+            edges
+                .iter()
+                .filter_map(|&(from, to, cost)| match *node {
+                    n if n == from => Some((to, cost)),
+                    n if n == to => Some((from, cost)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(dijkstra(['a'], neighbours, |n| *n == 'e'), Some(('e', 20)));
+        assert_eq!(
+            dijkstra(['a', 'd'], neighbours, |n| *n == 'e'),
+            Some(('e', 6))
+        );
+        assert_eq!(
+            dijkstra(['a'], neighbours, |n| *n == 'z'),
+            None::<(char, u32)>
+        );
+    }
+}